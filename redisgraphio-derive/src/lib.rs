@@ -0,0 +1,239 @@
+//! Derive macros for [redisgraphio](https://docs.rs/redisgraphio).
+//! Use them through the `derive` feature of redisgraphio instead of depending on this crate directly.
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, LitInt, LitStr};
+
+/// Derives `FromGraphValue` for a struct by mapping its fields positionally
+/// from a `GraphValue::Array` e.g. a row of `RETURN 1, 'a'`.
+///
+/// The position of a field can be overridden with `#[graph(index = N)]`.
+///
+/// ```
+/// use redisgraphio::{from_graph_value, FromGraphValue, GraphValue};
+///
+/// #[derive(FromGraphValue)]
+/// struct Row {
+///     #[graph(index = 1)]
+///     name: String,
+///     #[graph(index = 0)]
+///     age: i64,
+/// }
+///
+/// let row: Row = from_graph_value(GraphValue::Array(vec![
+///     GraphValue::Integer(42),
+///     GraphValue::String("Alice".to_owned()),
+/// ])).unwrap();
+/// assert_eq!((row.name.as_str(), row.age), ("Alice", 42));
+/// ```
+///
+/// Indices which are out of bounds or used more than once are rejected at compile time
+/// ```compile_fail
+/// #[derive(redisgraphio::FromGraphValue)]
+/// struct Row {
+///     #[graph(index = 2)]
+///     name: String,
+///     age: i64,
+/// }
+/// ```
+/// ```compile_fail
+/// #[derive(redisgraphio::FromGraphValue)]
+/// struct Row {
+///     #[graph(index = 1)]
+///     name: String,
+///     age: i64,
+/// }
+/// ```
+///
+/// So are unsupported attributes
+/// ```compile_fail
+/// #[derive(redisgraphio::FromGraphValue)]
+/// struct Row {
+///     #[graph(position = 0)]
+///     name: String,
+/// }
+/// ```
+/// ```compile_fail
+/// #[derive(redisgraphio::FromGraphValue)]
+/// struct Row {
+///     #[graph(rename = "key")]
+///     name: String,
+/// }
+/// ```
+#[proc_macro_derive(FromGraphValue, attributes(graph))]
+pub fn derive_from_graph_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_graph_value_impl(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `FromGraphValue` for a struct with named fields by looking up each field
+/// in a `GraphValue::Map` e.g. a row of `RETURN {name: n.name, age: n.age}`.
+///
+/// The key of a field can be overridden with `#[graph(rename = "key")]`.
+/// Missing keys are converted from `GraphValue::Null` so they only work for `Option` fields,
+/// other fields fail with `GraphError::MissingColumn` naming the missing key.
+#[proc_macro_derive(FromGraphMap, attributes(graph))]
+pub fn derive_from_graph_map(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_graph_map_impl(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn from_graph_value_impl(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(Error::new(input.span(), "FromGraphValue can only be derived for structs")),
+    };
+
+    let field_count = fields.len();
+    let mut indices = Vec::with_capacity(field_count);
+    for (position, field) in fields.iter().enumerate() {
+        let attrs = graph_attrs(&field.attrs)?;
+        if let Some(rename) = attrs.rename {
+            return Err(Error::new(rename.span(), "rename is only supported by FromGraphMap"));
+        }
+        let index = attrs.index.unwrap_or(position);
+        if index >= field_count {
+            return Err(Error::new(field.span(), format!("index {} is out of bounds for {} fields", index, field_count)));
+        }
+        if indices.contains(&index) {
+            return Err(Error::new(field.span(), format!("index {} is used more than once", index)));
+        }
+        indices.push(index);
+    }
+
+    let values = indices.iter().map(|index| quote! {
+        ::redisgraphio::from_graph_value(::std::mem::replace(&mut items[#index], ::redisgraphio::GraphValue::Null))?
+    });
+    let construct = match fields {
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|field| &field.ident);
+            quote! { #name { #(#idents: #values),* } }
+        }
+        Fields::Unnamed(_) => quote! { #name ( #(#values),* ) },
+        Fields::Unit => quote! { #name },
+    };
+
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(syn::parse_quote!(::redisgraphio::FromGraphValue));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let type_name = name.to_string();
+
+    Ok(quote! {
+        impl #impl_generics ::redisgraphio::FromGraphValue for #name #ty_generics #where_clause {
+            #[allow(unused_mut, unused_variables)]
+            fn from_graph_value(value: ::redisgraphio::GraphValue) -> ::redisgraphio::__private::RedisResult<Self> {
+                match value {
+                    ::redisgraphio::GraphValue::Array(mut items) => {
+                        if items.len() != #field_count {
+                            return Err(::redisgraphio::GraphError::LengthMismatch.with_detail(format!(
+                                "Wrong length to create {} from {:?}", #type_name, &items
+                            )));
+                        }
+                        Ok(#construct)
+                    }
+                    value => Err(::redisgraphio::GraphError::TypeMismatch.with_detail(format!(
+                        "Cant convert {} to {}: {}", value.type_name(), #type_name, value
+                    ))),
+                }
+            }
+        }
+    })
+}
+
+fn from_graph_map_impl(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            fields => return Err(Error::new(fields.span(), "FromGraphMap can only be derived for structs with named fields")),
+        },
+        _ => return Err(Error::new(input.span(), "FromGraphMap can only be derived for structs")),
+    };
+
+    let mut idents = Vec::with_capacity(fields.len());
+    let mut keys = Vec::with_capacity(fields.len());
+    for field in fields {
+        let attrs = graph_attrs(&field.attrs)?;
+        if attrs.index.is_some() {
+            return Err(Error::new(field.span(), "index is only supported by FromGraphValue"));
+        }
+        let ident = field.ident.as_ref().expect("named field");
+        let key = attrs.rename.map_or_else(
+            || ident.to_string().trim_start_matches("r#").to_owned(),
+            |rename| rename.value(),
+        );
+        if keys.contains(&key) {
+            return Err(Error::new(field.span(), format!("key {:?} is used more than once", key)));
+        }
+        idents.push(ident);
+        keys.push(key);
+    }
+
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(syn::parse_quote!(::redisgraphio::FromGraphValue));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let type_name = name.to_string();
+
+    Ok(quote! {
+        impl #impl_generics ::redisgraphio::FromGraphValue for #name #ty_generics #where_clause {
+            #[allow(unused_mut, unused_variables)]
+            fn from_graph_value(value: ::redisgraphio::GraphValue) -> ::redisgraphio::__private::RedisResult<Self> {
+                match value {
+                    ::redisgraphio::GraphValue::Map(map) => {
+                        let mut map = map.into_inner();
+                        Ok(#name {
+                            #(#idents: match map.remove(#keys) {
+                                Some(value) => ::redisgraphio::from_graph_value(value)?,
+                                None => ::redisgraphio::from_graph_value(::redisgraphio::GraphValue::Null).map_err(|_| {
+                                    ::redisgraphio::GraphError::MissingColumn.with_detail(format!(
+                                        "missing key {:?} for {}", #keys, #type_name
+                                    ))
+                                })?,
+                            }),*
+                        })
+                    }
+                    value => Err(::redisgraphio::GraphError::TypeMismatch.with_detail(format!(
+                        "Cant convert {} to {}: {}", value.type_name(), #type_name, value
+                    ))),
+                }
+            }
+        }
+    })
+}
+
+#[derive(Default)]
+struct GraphAttrs {
+    index: Option<usize>,
+    rename: Option<LitStr>,
+}
+
+/// Parses `#[graph(index = N)]` and `#[graph(rename = "key")]`
+fn graph_attrs(attrs: &[syn::Attribute]) -> syn::Result<GraphAttrs> {
+    let mut parsed = GraphAttrs::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("graph")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("index") {
+                parsed.index = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                Ok(())
+            } else if meta.path.is_ident("rename") {
+                parsed.rename = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported graph attribute"))
+            }
+        })?;
+    }
+    Ok(parsed)
+}
@@ -1,51 +1,352 @@
-use redis::{aio::ConnectionLike, RedisFuture, cmd};
+use redis::{aio::ConnectionLike, RedisFuture, Value, cmd};
+use std::collections::HashMap;
 
-use crate::{types::GraphQuery, FromGraphValue, GraphResponse, query};
+use crate::{parse::NodeIdRows, sync::{create_index_query, create_nodes_query, drop_index_query, graph_module_version, indexes_query, rows_to_maps}, types::GraphQuery, FromGraphValue, GraphError, GraphIndex, GraphStatistics, GraphValue, Parameter, GraphResponse, GraphRowStream, ProfileStep, SlowlogEntry, query};
 
 
 /// Implements redis graph related commands for an asynchronous connection
+///
+/// ## Cancellation
+/// Dropping one of the returned futures before it completes cancels the query on the client side only.
+/// A plain [`redis::aio::Connection`] is not cancel safe: the reply of the dropped query is still read
+/// by the next command on that connection. Wrap it in a [`CancelSafe`](crate::CancelSafe) to fail with an error instead.
+/// [`redis::aio::MultiplexedConnection`] and the `ConnectionManager` discard replies of dropped requests
+/// and can be reused safely, which makes them the better choice when futures may be cancelled e.g. by timeouts
 pub trait AsyncGraphCommands: ConnectionLike + Send + Sized {
     /// Send a graph query asynchronously
     fn graph_query<'a, Q, RT>(
         &'a mut self,
         graph: &'a str,
         query: Q,
-    ) -> RedisFuture<GraphResponse<RT>>
+    ) -> RedisFuture<'a, GraphResponse<RT>>
     where 
         Q: Into<GraphQuery> + Send + 'a,
         RT: FromGraphValue
     {
         Box::pin(async move {
-            let query = query.into();
-            cmd(query.read_type())
+            query.into().to_cmd(graph)?.query_async(self).await
+        })
+    }
+
+    /// Send a graph query asynchronously and parse the rows lazily while consuming the returned stream
+    fn graph_query_stream<'a, Q, RT>(
+        &'a mut self,
+        graph: &'a str,
+        query: Q,
+    ) -> RedisFuture<'a, GraphRowStream<RT>>
+    where 
+        Q: Into<GraphQuery> + Send + 'a,
+        RT: FromGraphValue
+    {
+        Box::pin(async move {
+            query.into().to_cmd(graph)?.query_async(self).await
+        })
+    }
+
+    /// Send a graph query asynchronously with additional arguments after `--compact` e.g. flags this crate does not support yet.
+    /// The query string is sent as is without any parameters
+    fn graph_query_raw<'a, RT: FromGraphValue>(
+        &'a mut self,
+        graph: &'a str,
+        query: &'a str,
+        extra_args: &'a [&'a str],
+    ) -> RedisFuture<'a, GraphResponse<RT>> {
+        Box::pin(async move {
+            cmd("GRAPH.QUERY")
                 .arg(graph)
-                .arg(query.construct_query())
+                .arg(query)
                 .arg("--compact")
+                .arg(extra_args)
                 .query_async(self)
                 .await
         })
     }
 
-    /// Send a graph query asynchronously and ignore the result data
+    /// Send a graph query asynchronously and parse the rows as `GraphValue`s without failing on single cells.
+    /// Cells which can not be parsed are returned as `GraphValue::Unknown` so the rest of the row can still be inspected
+    fn graph_query_lenient<'a, Q>(
+        &'a mut self,
+        graph: &'a str,
+        query: Q,
+    ) -> RedisFuture<'a, GraphResponse<GraphValue>>
+    where 
+        Q: Into<GraphQuery> + Send + 'a,
+    {
+        Box::pin(async move {
+            let value: Value = query.into().to_cmd(graph)?.query_async(self).await?;
+            GraphResponse::parse_lenient(&value)
+        })
+    }
+
+    /// Send a graph query asynchronously like `graph_query` but fail with `GraphError::UnknownValueType` instead of
+    /// returning `GraphValue::Unknown` for values with a type id this client does not know about
+    fn graph_query_strict<'a, Q, RT>(
+        &'a mut self,
+        graph: &'a str,
+        query: Q,
+    ) -> RedisFuture<'a, GraphResponse<RT>>
+    where 
+        Q: Into<GraphQuery> + Send + 'a,
+        RT: FromGraphValue
+    {
+        Box::pin(async move {
+            let value: Value = query.into().to_cmd(graph)?.query_async(self).await?;
+            GraphResponse::parse_response_strict(&value)
+        })
+    }
+
+    /// Send a graph query asynchronously and return the first column of the first row or `None` if no rows were returned.
+    /// Fails if the rows have more than one column
+    fn graph_query_scalar<'a, Q, T>(
+        &'a mut self,
+        graph: &'a str,
+        query: Q,
+    ) -> RedisFuture<'a, Option<T>>
+    where 
+        Q: Into<GraphQuery> + Send + 'a,
+        T: FromGraphValue
+    {
+        Box::pin(async move {
+            let data: Vec<(T,)> = self.graph_query(graph, query).await?.data;
+            Ok(data.into_iter().next().map(|(value,)| value))
+        })
+    }
+
+    /// Send a graph query asynchronously and collect the parsed rows into any collection e.g. a `HashSet`
+    /// without building a `Vec` of them first
+    fn graph_query_collect<'a, Q, RT, C>(
+        &'a mut self,
+        graph: &'a str,
+        query: Q,
+    ) -> RedisFuture<'a, C>
+    where 
+        Q: Into<GraphQuery> + Send + 'a,
+        RT: FromGraphValue,
+        C: FromIterator<RT> + 'a,
+    {
+        Box::pin(async move {
+            let mut stream: GraphRowStream<RT> = self.graph_query_stream(graph, query).await?;
+            std::iter::from_fn(|| stream.next_row()).collect()
+        })
+    }
+
+    /// Send a graph query asynchronously which returns a single node column and return only the id and label ids of each node.
+    /// The properties of the nodes are skipped instead of being parsed
+    fn graph_query_node_ids<'a, Q>(
+        &'a mut self,
+        graph: &'a str,
+        query: Q,
+    ) -> RedisFuture<'a, Vec<(i64, Vec<i64>)>>
+    where 
+        Q: Into<GraphQuery> + Send + 'a,
+    {
+        Box::pin(async move {
+            let rows: NodeIdRows = query.into().to_cmd(graph)?.query_async(self).await?;
+            Ok(rows.0)
+        })
+    }
+
+    /// Send a graph query asynchronously and return every row as a map from the column names to the returned values
+    fn graph_query_maps<'a, Q>(
+        &'a mut self,
+        graph: &'a str,
+        query: Q,
+    ) -> RedisFuture<'a, Vec<HashMap<String, GraphValue>>>
+    where 
+        Q: Into<GraphQuery> + Send + 'a,
+    {
+        Box::pin(async move {
+            Ok(rows_to_maps(self.graph_query(graph, query).await?))
+        })
+    }
+
+    /// Send a graph query asynchronously and return the only row or `None` if no rows were returned.
+    /// Fails if more than one row was returned
+    fn graph_query_one<'a, Q, RT>(
+        &'a mut self,
+        graph: &'a str,
+        query: Q,
+    ) -> RedisFuture<'a, Option<RT>>
+    where 
+        Q: Into<GraphQuery> + Send + 'a,
+        RT: FromGraphValue
+    {
+        Box::pin(async move {
+            let mut data: Vec<RT> = self.graph_query(graph, query).await?.data;
+            if data.len() > 1 {
+                return Err(GraphError::LengthMismatch.with_detail(format!("Expected at most one row but got {}", data.len())));
+            }
+            Ok(data.pop())
+        })
+    }
+
+    /// Send a graph query asynchronously and ignore the result data.
+    /// The header and statistics are kept but values returned by the query e.g. `CREATE (n) RETURN id(n)`
+    /// are dropped, use `graph_query` to keep them
     fn graph_query_void<'a, Q>(
         &'a mut self,
         graph: &'a str,
         query: Q,
-    ) -> RedisFuture<GraphResponse<()>>
+    ) -> RedisFuture<'a, GraphResponse<()>>
     where 
         Q: Into<GraphQuery> + Send + 'a,
+    {
+        Box::pin(async move {
+            query.into().to_cmd(graph)?.query_async(self).await
+        })
+    }
+
+    /// Send a graph query asynchronously and return its parsed statistics e.g. the number of created nodes
+    fn graph_execute<'a, Q>(
+        &'a mut self,
+        graph: &'a str,
+        query: Q,
+    ) -> RedisFuture<'a, GraphStatistics>
+    where
+        Q: Into<GraphQuery> + Send + 'a,
+    {
+        Box::pin(async move {
+            Ok(self.graph_query_void(graph, query).await?.get_statistics())
+        })
+    }
+
+    /// Constructs the execution plan of the query asynchronously without running it
+    /// Each item is one operation of the plan e.g. "Results" or "    All Node Scan | (n)"
+    fn graph_explain<'a, Q>(
+        &'a mut self,
+        graph: &'a str,
+        query: Q,
+    ) -> RedisFuture<'a, Vec<String>>
+    where
+        Q: Into<GraphQuery> + Send + 'a,
+    {
+        Box::pin(async move {
+            cmd("GRAPH.EXPLAIN")
+                .arg(graph)
+                .arg(query.into().checked_query()?)
+                .query_async(self)
+                .await
+        })
+    }
+
+    /// Runs the query asynchronously and returns the execution plan with the records produced and the execution time of each operation
+    fn graph_profile<'a, Q>(
+        &'a mut self,
+        graph: &'a str,
+        query: Q,
+    ) -> RedisFuture<'a, Vec<ProfileStep>>
+    where
+        Q: Into<GraphQuery> + Send + 'a,
     {
         Box::pin(async move {
             let query = query.into();
-            cmd(query.read_type())
+            cmd("GRAPH.PROFILE")
                 .arg(graph)
-                .arg(query.construct_query())
-                .arg("--compact")
+                .arg(query.checked_query()?)
                 .query_async(self)
                 .await
         })
     }
 
+    /// Deletes the graph and all its entities asynchronously
+    /// Returns the status message of redis graph e.g. "Graph removed, internal execution time: 0.01 milliseconds"
+    fn graph_delete<'a>(&'a mut self, graph: &'a str) -> RedisFuture<'a, String> {
+        Box::pin(async move {
+            cmd("GRAPH.DELETE")
+                .arg(graph)
+                .query_async(self)
+                .await
+        })
+    }
+
+    /// Creates a node with the label for every row in a single query asynchronously by passing the rows as one parameter
+    /// Returns the statistics of the query e.g. `nodes_created`
+    fn create_nodes<'a, P: Into<Parameter>>(
+        &'a mut self,
+        graph: &'a str,
+        label: &str,
+        rows: Vec<HashMap<String, P>>,
+    ) -> RedisFuture<'a, GraphStatistics> {
+        self.graph_execute(graph, create_nodes_query(label, rows))
+    }
+
+    /// Returns true if a key with the name of the graph exists asynchronously
+    fn graph_exists<'a>(&'a mut self, graph: &'a str) -> RedisFuture<'a, bool> {
+        Box::pin(async move {
+            cmd("EXISTS")
+                .arg(graph)
+                .query_async(self)
+                .await
+        })
+    }
+
+    /// Copies the graph and all its entities to a new graph asynchronously
+    /// Fails if the destination graph already exists
+    fn copy_graph<'a>(&'a mut self, src: &'a str, dest: &'a str) -> RedisFuture<'a, ()> {
+        Box::pin(async move {
+            cmd("GRAPH.COPY")
+                .arg(src)
+                .arg(dest)
+                .query_async(self)
+                .await
+        })
+    }
+
+    /// Returns the slowest queries of the graph asynchronously
+    fn slowlog<'a>(&'a mut self, graph: &'a str) -> RedisFuture<'a, Vec<SlowlogEntry>> {
+        Box::pin(async move {
+            cmd("GRAPH.SLOWLOG")
+                .arg(graph)
+                .query_async(self)
+                .await
+        })
+    }
+
+    /// Clears the slowlog of the graph asynchronously
+    fn slowlog_reset<'a>(&'a mut self, graph: &'a str) -> RedisFuture<'a, ()> {
+        Box::pin(async move {
+            cmd("GRAPH.SLOWLOG")
+                .arg(graph)
+                .arg("RESET")
+                .query_async(self)
+                .await
+        })
+    }
+
+    /// Returns the names of all graphs on the server asynchronously
+    fn list_graphs(&mut self) -> RedisFuture<'_, Vec<String>> {
+        Box::pin(async move {
+            cmd("GRAPH.LIST").query_async(self).await
+        })
+    }
+
+    /// Returns the version of the loaded redis graph module from `MODULE LIST` asynchronously e.g. "2.12.10"
+    fn graph_version(&mut self) -> RedisFuture<'_, String> {
+        Box::pin(async move {
+            let modules: Value = cmd("MODULE").arg("LIST").query_async(self).await?;
+            graph_module_version(&modules)
+        })
+    }
+
+    /// Creates an index on the property of nodes with the label asynchronously
+    /// Returns the statistics of the query e.g. `indices_created`
+    fn create_index<'a>(&'a mut self, graph: &'a str, label: &str, property: &str) -> RedisFuture<'a, GraphStatistics> {
+        self.graph_execute(graph, create_index_query(label, property))
+    }
+
+    /// Drops the index on the property of nodes with the label asynchronously
+    /// Returns the statistics of the query e.g. `indices_deleted`
+    fn drop_index<'a>(&'a mut self, graph: &'a str, label: &str, property: &str) -> RedisFuture<'a, GraphStatistics> {
+        self.graph_execute(graph, drop_index_query(label, property))
+    }
+
+    /// Returns all indexes of the graph asynchronously
+    fn indexes<'a>(&'a mut self, graph: &'a str) -> RedisFuture<'a, Vec<GraphIndex>> {
+        Box::pin(async move {
+            Ok(self.graph_query(graph, indexes_query()).await?.data)
+        })
+    }
+
     /// Returns a vector where the index is a label id and the value at that index is the corresponding label name
     fn labels<'a>(&'a mut self, graph: &'a str) -> RedisFuture<'a, Vec<String>> {
         Box::pin(async move {
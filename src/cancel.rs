@@ -0,0 +1,90 @@
+use redis::{aio::ConnectionLike, Cmd, ErrorKind, Pipeline, RedisError, RedisFuture, RedisResult, Value};
+
+/// Wraps an async connection which is not cancel safe like [`redis::aio::Connection`]
+/// so a dropped query can not hand its reply to the next command.
+///
+/// A request which is dropped before its reply was read poisons the connection.
+/// Every following request fails with a "Poisoned connection" error of kind `ClientError` instead of
+/// reading the stale reply, so the connection has to be replaced.
+/// The stale reply is not drained as it can not be read through [`ConnectionLike`]
+///
+/// ## Example
+/// ```no_run
+/// use redisgraphio::{AsyncGraphCommands, CancelSafe, query};
+///
+/// # async fn run() -> redis::RedisResult<()> {
+/// let client = redis::Client::open("redis://127.0.0.1/")?;
+/// let mut con = CancelSafe::new(client.get_async_connection().await?);
+/// let response = con.graph_query::<_, (i64,)>("graph", query!("Return 1")).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CancelSafe<C> {
+    inner: C,
+    in_flight: bool,
+}
+
+impl<C> CancelSafe<C> {
+    /// Wraps the connection
+    pub fn new(inner: C) -> CancelSafe<C> {
+        CancelSafe { inner, in_flight: false }
+    }
+
+    /// Whether a request was dropped before its reply was read
+    pub fn is_poisoned(&self) -> bool {
+        self.in_flight
+    }
+
+    /// Returns a reference to the wrapped connection
+    pub fn get_ref(&self) -> &C {
+        &self.inner
+    }
+
+    /// Returns the wrapped connection
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+
+    fn check_poisoned(&self) -> RedisResult<()> {
+        if self.in_flight {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "Poisoned connection",
+                "A previous request was cancelled before its reply was read".to_owned(),
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl<C: ConnectionLike + Send> ConnectionLike for CancelSafe<C> {
+    fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> RedisFuture<'a, Value> {
+        Box::pin(async move {
+            self.check_poisoned()?;
+            self.in_flight = true;
+            let value = self.inner.req_packed_command(cmd).await;
+            self.in_flight = false;
+            value
+        })
+    }
+
+    fn req_packed_commands<'a>(
+        &'a mut self,
+        cmd: &'a Pipeline,
+        offset: usize,
+        count: usize,
+    ) -> RedisFuture<'a, Vec<Value>> {
+        Box::pin(async move {
+            self.check_poisoned()?;
+            self.in_flight = true;
+            let values = self.inner.req_packed_commands(cmd, offset, count).await;
+            self.in_flight = false;
+            values
+        })
+    }
+
+    fn get_db(&self) -> i64 {
+        self.inner.get_db()
+    }
+}
@@ -0,0 +1,85 @@
+use std::{error::Error, fmt};
+
+use redis::{ErrorKind, RedisError};
+
+/// Kinds of errors that occur while parsing a redis graph response.
+///
+/// The errors are returned as [`RedisError`]s of kind `TypeError` so they work with every `RedisResult`.
+/// Use [`GraphError::from_redis_error`] to find out which of these errors occurred
+///
+/// ## Example
+/// ```
+/// use redisgraphio::{from_graph_value, GraphError, GraphValue};
+///
+/// let err = from_graph_value::<bool>(GraphValue::Integer(1)).unwrap_err();
+/// assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::TypeMismatch));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GraphError {
+    /// The value can not be converted into the requested type
+    TypeMismatch,
+    /// The number of values does not match the requested tuple or struct
+    LengthMismatch,
+    /// The integer is out of the range of the requested integer type
+    IntegerOverflow,
+    /// Redis graph returned a value type id this client does not know
+    UnknownValueType,
+    /// A requested column is not part of the response
+    MissingColumn,
+    /// The reply does not have the structure of a redis graph response
+    InvalidResponse,
+    /// A query parameter can not be sent to redis graph e.g. because its name is not a valid identifier
+    InvalidParameter,
+}
+
+const ALL: [GraphError; 7] = [
+    GraphError::TypeMismatch,
+    GraphError::LengthMismatch,
+    GraphError::IntegerOverflow,
+    GraphError::UnknownValueType,
+    GraphError::MissingColumn,
+    GraphError::InvalidResponse,
+    GraphError::InvalidParameter,
+];
+
+impl GraphError {
+    /// Static description which is used as the description of the [`RedisError`]
+    pub const fn description(self) -> &'static str {
+        match self {
+            GraphError::TypeMismatch => "Type mismatch",
+            GraphError::LengthMismatch => "Length mismatch",
+            GraphError::IntegerOverflow => "Integer overflow",
+            GraphError::UnknownValueType => "Unknown value type",
+            GraphError::MissingColumn => "Missing column",
+            GraphError::InvalidResponse => "Invalid response",
+            GraphError::InvalidParameter => "Invalid parameter",
+        }
+    }
+
+    /// Creates a [`RedisError`] of this kind with a detailed message
+    pub fn with_detail<S: Into<String>>(self, detail: S) -> RedisError {
+        (ErrorKind::TypeError, self.description(), detail.into()).into()
+    }
+
+    /// Returns which error occurred if the [`RedisError`] was created by this crate
+    pub fn from_redis_error(err: &RedisError) -> Option<GraphError> {
+        if err.kind() != ErrorKind::TypeError {
+            return None;
+        }
+        #[allow(deprecated)]
+        let description = err.description();
+        ALL.into_iter().find(|kind| kind.description() == description)
+    }
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+impl From<GraphError> for RedisError {
+    fn from(err: GraphError) -> Self {
+        (ErrorKind::TypeError, err.description()).into()
+    }
+}
@@ -1,6 +1,6 @@
 use redis::{RedisResult, ErrorKind, RedisError};
 
-use crate::{FromGraphValue, GraphValue};
+use crate::{FromGraphValue, FromGraphValueRef, GraphValue};
 
 
 /// Helper macro to apply a macro to each following type
@@ -20,6 +20,11 @@ pub fn from_graph_value<T: FromGraphValue>(value: GraphValue) -> RedisResult<T>
     FromGraphValue::from_graph_value(value)
 }
 
+/// Helper for borrowing a type that implements [`FromGraphValueRef`] from a [`GraphValue`]
+pub fn from_graph_value_ref<'a, T: FromGraphValueRef<'a>>(value: &'a GraphValue) -> RedisResult<T> {
+    FromGraphValueRef::from_graph_value_ref(value)
+}
+
 /// Helper for creating a Rediserror
 pub fn create_rediserror(desc: &str) -> RedisError {
     (
@@ -29,11 +34,53 @@ pub fn create_rediserror(desc: &str) -> RedisError {
     ).into()
 }
 
+/// Escapes a string so it can be placed between double quotes in a cypher query.
+/// Unlike `str::escape_default` this only produces escape sequences the cypher parser understands
+pub(crate) fn escape_cypher_string(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len());
+    for c in string.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\'' => escaped.push_str("\\'"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\u{8}' => escaped.push_str("\\b"),
+            '\u{c}' => escaped.push_str("\\f"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04X}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Whether the name is a plain identifier which can be used unquoted e.g. as a parameter name
+pub(crate) fn is_cypher_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Identifiers like map keys or labels are emitted unquoted unless they are not a plain identifier
+/// in which case they get quoted with backticks to prevent injections
+pub(crate) fn cypher_identifier(key: &str) -> String {
+    if is_cypher_identifier(key) {
+        key.to_owned()
+    } else {
+        format!("`{}`", key.replace('`', "``"))
+    }
+}
+
 /// Macro for creating a GraphQuery
 /// ## Diffrent usecases
 /// ```
+/// # use redisgraphio::query;
 /// query!("query string"); // Normal query
 /// query!("query string", true); // Normal read only query
+/// let read_only = false;
+/// query!("query string", read_only); // Read only flag computed at runtime
+/// query!(format!("query string {}", 5)); // Query string built at runtime
 /// query!(
 ///     "query string $param",
 ///     {
@@ -48,29 +95,79 @@ pub fn create_rediserror(desc: &str) -> RedisError {
 ///     },
 ///     true
 /// ); // Query with parameters and read only
+/// query!("query string", timeout = 1000); // Query with a timeout of 1000 milliseconds
+/// query!("query string $param", {"param" => 5}, true, timeout = 1000); // Timeout after every other argument
 /// ```
 #[macro_export]
 macro_rules! query {
-    ( $s:expr $(, $ro:literal)?) => {{
-        #[allow(unused_assignments, unused_mut)]
-        let mut read_only = false;
-        $(
-            read_only = $ro;
-        )?
+    (@build $s:expr, [$( ($k:expr, $v:expr) )*], $ro:expr, $t:expr) => {
         $crate::GraphQuery {
-            query: $s, read_only, params: vec![]
-        }
-    }};
-    ( $s:expr, { $( $k:expr => $v:expr ),* } $(, $ro:literal)?) => {{
-        #[allow(unused_assignments, unused_mut)]
-        let mut read_only = false;
-        $(
-            read_only = $ro;
-        )?
-        $crate::GraphQuery {
-            query: $s, read_only, params: vec![$(
+            query: ::std::borrow::Cow::from($s), read_only: $ro, timeout: $t, params: vec![$(
                 ($k, $crate::Parameter::from($v)),
             )*]
         }
-    }}
+    };
+    ( $s:expr, { $( $k:expr => $v:expr ),* }, timeout = $t:expr) => {
+        $crate::query!(@build $s, [$( ($k, $v) )*], false, Some($t))
+    };
+    ( $s:expr, { $( $k:expr => $v:expr ),* }, $ro:expr, timeout = $t:expr) => {
+        $crate::query!(@build $s, [$( ($k, $v) )*], $ro, Some($t))
+    };
+    ( $s:expr, { $( $k:expr => $v:expr ),* }, $ro:expr) => {
+        $crate::query!(@build $s, [$( ($k, $v) )*], $ro, None)
+    };
+    ( $s:expr, { $( $k:expr => $v:expr ),* }) => {
+        $crate::query!(@build $s, [$( ($k, $v) )*], false, None)
+    };
+    ( $s:expr, timeout = $t:expr) => {
+        $crate::query!(@build $s, [], false, Some($t))
+    };
+    ( $s:expr, $ro:expr, timeout = $t:expr) => {
+        $crate::query!(@build $s, [], $ro, Some($t))
+    };
+    ( $s:expr, $ro:expr) => {
+        $crate::query!(@build $s, [], $ro, None)
+    };
+    ( $s:expr) => {
+        $crate::query!(@build $s, [], false, None)
+    };
+}
+
+/// Implements FromGraphValue for a newtype by converting the wrapped type.
+/// An array of a single value e.g. a row of `RETURN id(n)` is unwrapped and the single value is converted instead
+/// if that value is an array itself or if the wrapped type can not be converted from arrays
+/// ## Example
+/// ```
+/// use redisgraphio::{from_graph_value, impl_from_graph_value_newtype, GraphValue};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Id(i64);
+/// impl_from_graph_value_newtype!(Id, i64);
+///
+/// assert_eq!(from_graph_value::<Id>(GraphValue::Integer(1)).unwrap(), Id(1));
+/// assert_eq!(from_graph_value::<Id>(GraphValue::Array(vec![GraphValue::Integer(2)])).unwrap(), Id(2));
+/// ```
+#[macro_export]
+macro_rules! impl_from_graph_value_newtype {
+    ($name:ident, $inner:ty) => {
+        impl $crate::FromGraphValue for $name {
+            fn from_graph_value(value: $crate::GraphValue) -> $crate::__private::RedisResult<Self> {
+                match value {
+                    $crate::GraphValue::Array(mut items) if items.len() == 1 => {
+                        // Only wrapped types which fail on arrays with a type mismatch can not be built from the array itself
+                        let accepts_arrays = !matches!(
+                            $crate::from_graph_value::<$inner>($crate::GraphValue::Array(vec![])),
+                            Err(err) if $crate::GraphError::from_redis_error(&err) == Some($crate::GraphError::TypeMismatch)
+                        );
+                        if accepts_arrays && !matches!(items[0], $crate::GraphValue::Array(_)) {
+                            $crate::from_graph_value::<$inner>($crate::GraphValue::Array(items)).map($name)
+                        } else {
+                            $crate::from_graph_value::<$inner>(items.remove(0)).map($name)
+                        }
+                    }
+                    value => $crate::from_graph_value::<$inner>(value).map($name),
+                }
+            }
+        }
+    };
 }
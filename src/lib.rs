@@ -5,17 +5,49 @@ mod parse;
 mod sync;
 mod helpers;
 mod types;
+mod schema;
+mod pipeline;
+mod error;
+mod stream;
+mod retry;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 #[cfg(test)]
 mod tests;
 
+// Lets the derive macros refer to `::redisgraphio` inside of this crates tests
+#[cfg(test)]
+extern crate self as redisgraphio;
+
 pub use crate::types::*;
-pub use crate::sync::GraphCommands;
+pub use crate::sync::{DynGraphCommands, GraphCommands};
 pub use crate::parse::*;
-pub use crate::helpers::{from_graph_value, create_rediserror};
+pub use crate::schema::GraphSchema;
+pub use crate::pipeline::GraphPipeline;
+pub use crate::error::GraphError;
+pub use crate::stream::GraphRowStream;
+pub use crate::retry::RetryPolicy;
+pub use crate::helpers::{from_graph_value, from_graph_value_ref, create_rediserror};
+
+#[cfg(feature = "derive")]
+pub use redisgraphio_derive::{FromGraphMap, FromGraphValue};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use redis::RedisResult;
+}
 
 #[cfg(any(feature = "tokio-comp", feature = "async-std-comp"))]
 mod aio;
+#[cfg(any(feature = "tokio-comp", feature = "async-std-comp"))]
+mod cancel;
 
 #[cfg(any(feature = "tokio-comp", feature = "async-std-comp"))]
 pub use crate::aio::AsyncGraphCommands;
+#[cfg(any(feature = "tokio-comp", feature = "async-std-comp"))]
+pub use crate::cancel::CancelSafe;
+
+/// Re-export of the deadpool-redis version whose pooled connections implement [`AsyncGraphCommands`]
+#[cfg(feature = "deadpool-comp")]
+pub use deadpool_redis;
 
@@ -1,16 +1,23 @@
 use indexmap::IndexMap;
-use redis::{from_redis_value, FromRedisValue, RedisResult, Value};
+use redis::{from_redis_value, FromRedisValue, RedisError, RedisResult, Value};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashMap,
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    fmt,
+    rc::Rc,
+    sync::Arc,
 };
 
 use crate::{
-    from_graph_value,
-    helpers::{create_rediserror, apply_macro}
+    from_graph_value, from_graph_value_ref,
+    helpers::{apply_macro, escape_cypher_string},
+    GraphError, GraphSchema,
 };
 
 /// [Official enum](https://github.com/RedisGraph/RedisGraph/blob/master/src/resultset/formatters/resultset_formatter.h#L20-L33) from redis-graph 
-mod types {
+pub(crate) mod types {
     pub const VALUE_UNKNOWN: i64 = 0;
     pub const VALUE_NULL: i64 = 1;
     pub const VALUE_STRING: i64 = 2;
@@ -26,9 +33,14 @@ mod types {
 }
 
 /// An enum containing every possible type that can be returned by redisgraph
+///
+/// With the `serde` feature it serializes as `{"type": "Integer", "value": 5}`.
+/// Serializing an `Unknown` value fails as it has no stable representation
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(tag = "type", content = "value"))]
 pub enum GraphValue {
     /// Value is Unknown and stored as a [redis::Value]
+    #[cfg_attr(feature = "serde", serde(skip))]
     Unknown(Value),
     /// A Map as returned by
     /// ```cypher
@@ -85,17 +97,100 @@ pub enum GraphValue {
     Null,
 }
 
+impl GraphValue {
+    /// Returns true if this is a `GraphValue::Null`
+    pub fn is_null(&self) -> bool {
+        matches!(self, GraphValue::Null)
+    }
+
+    /// Name of the variant e.g. "Node" or "Integer" for logging and error messages
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            GraphValue::Unknown(_) => "Unknown",
+            GraphValue::Map(_) => "Map",
+            GraphValue::Point(_) => "Point",
+            GraphValue::Path(_) => "Path",
+            GraphValue::Node(_) => "Node",
+            GraphValue::Relation(_) => "Relation",
+            GraphValue::Array(_) => "Array",
+            GraphValue::Integer(_) => "Integer",
+            GraphValue::Double(_) => "Double",
+            GraphValue::String(_) => "String",
+            GraphValue::Boolean(_) => "Boolean",
+            GraphValue::Null => "Null",
+        }
+    }
+}
+
+/// Error for a value that can not be converted into the target type
+fn type_mismatch(value: &GraphValue, target: &str) -> RedisError {
+    GraphError::TypeMismatch.with_detail(format!("Cant convert {} to {}: {}", value.type_name(), target, value))
+}
+
+/// Implements a borrowing accessor for each variant which returns `None` for every other variant
+macro_rules! graph_value_accessors {
+    ($($name:ident: $variant:ident => $t:ty, |$v:ident| $map:expr;)*) => {
+        impl GraphValue {
+            $(
+                #[doc = concat!("Returns the value if this is a `GraphValue::", stringify!($variant), "`")]
+                pub fn $name(&self) -> Option<$t> {
+                    match self {
+                        GraphValue::$variant($v) => Some($map),
+                        _ => None,
+                    }
+                }
+            )*
+        }
+    };
+}
+
+graph_value_accessors! {
+    as_integer: Integer => i64, |v| *v;
+    as_double: Double => f64, |v| *v;
+    as_str: String => &str, |v| v.as_str();
+    as_bool: Boolean => bool, |v| *v;
+    as_array: Array => &[GraphValue], |v| v.as_slice();
+    as_map: Map => &GraphMap, |v| v;
+    as_point: Point => &GeoPoint, |v| v;
+    as_node: Node => &Node, |v| v;
+    as_relationship: Relation => &Relationship, |v| v;
+    as_path: Path => &GraphPath, |v| v;
+}
+
+/// Compares the value with a primitive which is only equal to the matching variant
+/// e.g. `GraphValue::Integer(1) == 1` but `GraphValue::Double(1.0) != 1`
+macro_rules! graph_value_eq {
+    ($($t:ty => $accessor:ident;)*) => {
+        $(
+            impl PartialEq<$t> for GraphValue {
+                fn eq(&self, other: &$t) -> bool {
+                    self.$accessor() == Some(*other)
+                }
+            }
+        )*
+    };
+}
+
+graph_value_eq! {
+    i64 => as_integer;
+    f64 => as_double;
+    bool => as_bool;
+    &str => as_str;
+}
+
 /// The type returned by the point method in cypher
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GeoPoint {
     /// latitude
-    pub latitude: f32,
+    pub latitude: f64,
     /// longitude
-    pub longitude: f32,
+    pub longitude: f64,
 }
 
 /// Map typed as returned by RETURN {a: 1}
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GraphMap(pub HashMap<String, GraphValue>);
 
 impl GraphMap {
@@ -111,10 +206,72 @@ impl GraphMap {
             None => Ok(None),
         }
     }
+
+    /// Same as `get` but borrows the value instead of cloning it
+    pub fn get_borrowed<'a, T: FromGraphValueRef<'a>>(&'a self, key: &str) -> RedisResult<Option<T>> {
+        match self.0.get(key) {
+            Some(val) => from_graph_value_ref(val),
+            None => Ok(None),
+        }
+    }
+
+    /// Borrows the value of a key without converting it
+    pub fn get_ref(&self, key: &str) -> Option<&GraphValue> {
+        self.0.get(key)
+    }
+
+    /// Returns true if the map contains the key
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+
+    /// Number of entries in the map
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the map has no entries
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the entries of the map in arbitrary order
+    pub fn iter(&self) -> std::collections::hash_map::Iter<'_, String, GraphValue> {
+        self.0.iter()
+    }
+
+    /// Iterates over the keys of the map in arbitrary order
+    pub fn keys(&self) -> std::collections::hash_map::Keys<'_, String, GraphValue> {
+        self.0.keys()
+    }
+
+    /// Iterates over the values of the map in arbitrary order
+    pub fn values(&self) -> std::collections::hash_map::Values<'_, String, GraphValue> {
+        self.0.values()
+    }
+}
+
+impl IntoIterator for GraphMap {
+    type Item = (String, GraphValue);
+    type IntoIter = std::collections::hash_map::IntoIter<String, GraphValue>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GraphMap {
+    type Item = (&'a String, &'a GraphValue);
+    type IntoIter = std::collections::hash_map::Iter<'a, String, GraphValue>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
 }
 
 /// Node Type
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Node {
     /// Redisgraph internal node id
     pub id: i64,
@@ -133,10 +290,31 @@ impl Node {
             properties,
         }
     }
+
+    /// Maps the label ids of the node to their names using the labels returned by `GraphCommands::labels`.
+    /// The mapping has to be fetched from the same graph and be up to date as ids can shift when labels change.
+    /// Ids which are not part of the mapping are skipped
+    pub fn resolve_labels<'a>(&self, graph_labels: &'a [String]) -> Vec<&'a str> {
+        self.label_ids
+            .iter()
+            .filter_map(|&id| graph_labels.get(usize::try_from(id).ok()?))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Names of the labels of the node looked up in the schema.
+    /// Ids which are not part of the schema are skipped
+    pub fn labels<'a>(&self, schema: &'a GraphSchema) -> Vec<&'a str> {
+        self.label_ids
+            .iter()
+            .filter_map(|&id| schema.label_name(id))
+            .collect()
+    }
 }
 
 /// Relationship Type
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Relationship {
     /// Redisgraph internal relationship id
     pub id: i64,
@@ -167,6 +345,11 @@ impl Relationship {
             properties,
         }
     }
+
+    /// Name of the relationship type looked up in the schema
+    pub fn type_name<'a>(&self, schema: &'a GraphSchema) -> Option<&'a str> {
+        schema.relationship_name(self.label_id)
+    }
 }
 
 /// Trait for unifying access to Node and Relationship properties
@@ -182,12 +365,83 @@ pub trait PropertyAccess {
         }
     }
 
+    /// Same as `get_property_by_label_id` but borrows the property instead of cloning it
+    fn get_property_ref_by_label_id<'a, T: FromGraphValueRef<'a>>(&'a self, label_id: i64) -> RedisResult<Option<T>> {
+        match self.properties().get(&label_id) {
+            Some(val) => from_graph_value_ref(val),
+            None => Ok(None),
+        }
+    }
+
+    /// get property by its name using the property keys returned by `GraphCommands::property_keys`.
+    /// Returns `Ok(None)` if the name is unknown or the entity does not have the property
+    fn get_property_by_name<T: FromGraphValue>(&self, name: &str, key_map: &[String]) -> RedisResult<Option<T>> {
+        match key_map.iter().position(|key| key == name) {
+            Some(label_id) => self.get_property_by_label_id(label_id as i64),
+            None => Ok(None),
+        }
+    }
+
+    /// Clones all properties into a map keyed by their names using the property keys returned by `GraphCommands::property_keys`.
+    /// Properties with ids which are not part of the key map are skipped
+    fn properties_by_name(&self, key_map: &[String]) -> HashMap<String, GraphValue> {
+        self.properties()
+            .iter()
+            .filter_map(|(&id, value)| {
+                let name = key_map.get(usize::try_from(id).ok()?)?;
+                Some((name.clone(), value.clone()))
+            })
+            .collect()
+    }
+
+    /// Converts the properties into a type keyed by property names e.g. a struct deriving `FromGraphMap`.
+    /// The ids of the properties are resolved with the property keys returned by `GraphCommands::property_keys`
+    /// or `GraphSchema::property_keys`, so the key map has to be fetched from the same graph beforehand.
+    /// Properties the entity does not have are passed to the conversion as `GraphValue::Null`
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # use redisgraphio::*;
+    /// # fn example(con: &mut redis::Connection) -> redis::RedisResult<()> {
+    /// struct User {
+    ///     name: String,
+    ///     age: i64,
+    /// }
+    ///
+    /// // With the `derive` feature `#[derive(FromGraphMap)]` can generate this impl
+    /// impl FromGraphValue for User {
+    ///     fn from_graph_value(value: GraphValue) -> redis::RedisResult<Self> {
+    ///         let mut map = from_graph_value::<GraphMap>(value)?.into_inner();
+    ///         Ok(User {
+    ///             name: from_graph_value(map.remove("name").unwrap_or(GraphValue::Null))?,
+    ///             age: from_graph_value(map.remove("age").unwrap_or(GraphValue::Null))?,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let schema = GraphSchema::load(con, "graph")?;
+    /// let users: Vec<(Node,)> = con.graph_query("graph", query!("MATCH (u:User) RETURN u", true))?.data;
+    /// for (node,) in users {
+    ///     let user: User = node.properties_into(schema.property_keys())?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn properties_into<T: FromGraphValue>(&self, key_map: &[String]) -> RedisResult<T> {
+        from_graph_value(GraphValue::Map(GraphMap(self.properties_by_name(key_map))))
+    }
+
     /// gets a property by its order of definition
     /// Note when relying on property order make sure every CREATE has the same order of these properties
     fn get_property_by_index<T: FromGraphValue>(&self, idx: usize) -> RedisResult<T> {
         from_graph_value(self.properties()[idx].clone())
     }
 
+    /// Same as `get_property_by_index` but borrows the property instead of cloning it
+    fn get_property_ref_by_index<'a, T: FromGraphValueRef<'a>>(&'a self, idx: usize) -> RedisResult<T> {
+        from_graph_value_ref(&self.properties()[idx])
+    }
+
     /// get property values in the order they were defined
     fn property_values<T: FromGraphValue>(&self) -> RedisResult<T> {
         from_graph_value(GraphValue::Array(
@@ -227,6 +481,7 @@ impl PropertyAccess for Relationship {
 
 /// Type for graph paths as returned by MATCH p=(\:A)-[\:B]->(\:C) RETURN p
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GraphPath {
     /// Nodes of the GraphPath
     pub nodes: Vec<Node>,
@@ -234,6 +489,112 @@ pub struct GraphPath {
     pub relationships: Vec<Relationship>,
 }
 
+/// Formats the value similar to a cypher literal e.g. `[1, "a", {b: null}]`.
+/// Labels, relationship types and property keys are shown by their ids
+impl fmt::Display for GraphValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphValue::Unknown(value) => write!(f, "{:?}", value),
+            GraphValue::Map(map) => map.fmt(f),
+            GraphValue::Point(point) => point.fmt(f),
+            GraphValue::Path(path) => path.fmt(f),
+            GraphValue::Node(node) => node.fmt(f),
+            GraphValue::Relation(rel) => rel.fmt(f),
+            GraphValue::Array(values) => {
+                f.write_str("[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    value.fmt(f)?;
+                }
+                f.write_str("]")
+            }
+            GraphValue::Integer(int) => int.fmt(f),
+            GraphValue::Double(double) => write!(f, "{:?}", double),
+            GraphValue::String(string) => write!(f, r#""{}""#, escape_cypher_string(string)),
+            GraphValue::Boolean(boolean) => boolean.fmt(f),
+            GraphValue::Null => f.write_str("null"),
+        }
+    }
+}
+
+/// Writes `{key: value, ...}`
+fn fmt_entries<'a, K: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    entries: impl Iterator<Item = (K, &'a GraphValue)>,
+) -> fmt::Result {
+    f.write_str("{")?;
+    for (i, (key, value)) in entries.enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{}: {}", key, value)?;
+    }
+    f.write_str("}")
+}
+
+/// Entries are sorted by key to make the output deterministic
+impl fmt::Display for GraphMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut entries: Vec<_> = self.0.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        fmt_entries(f, entries.into_iter())
+    }
+}
+
+impl fmt::Display for GeoPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "point({{latitude: {:?}, longitude: {:?}}})", self.latitude, self.longitude)
+    }
+}
+
+/// Formats the node as `(:label_id {property_id: value})`
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        for label_id in self.label_ids.iter() {
+            write!(f, ":{}", label_id)?;
+        }
+        if !self.properties.is_empty() {
+            f.write_str(" ")?;
+            fmt_entries(f, self.properties.iter())?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// Formats the relationship as `[:label_id {property_id: value}]`
+impl fmt::Display for Relationship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[:{}", self.label_id)?;
+        if !self.properties.is_empty() {
+            f.write_str(" ")?;
+            fmt_entries(f, self.properties.iter())?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// Formats the path as `(:0)-[:0]->(:1)` using the direction of each relationship
+impl fmt::Display for GraphPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, node) in self.nodes.iter().enumerate() {
+            if i > 0 {
+                if let Some(rel) = self.relationships.get(i - 1) {
+                    if rel.src == node.id {
+                        write!(f, "<-{}-", rel)?;
+                    } else {
+                        write!(f, "-{}->", rel)?;
+                    }
+                }
+            }
+            node.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
 /// Trait for converting the response to an arbitray type which implents the trait
 /// This is similar to the FromRedisValue trait from redis
 /// 
@@ -258,6 +619,9 @@ pub struct GraphPath {
 /// let con = // Connection to redis
 /// let data: Vec<MyType> = con.graph_query("graphname", query!("RETURN 1, ['a', 'b']"))?.data;
 /// ```
+///
+/// Tuples are implemented for up to 16 elements. Rows with more columns can be parsed
+/// into a `Vec<GraphValue>`, nested tuples are not supported as each row is a flat array
 pub trait FromGraphValue: Sized {
     /// Converts the GraphValue to the implementing Type
     fn from_graph_value(value: GraphValue) -> RedisResult<Self>;
@@ -265,16 +629,19 @@ pub trait FromGraphValue: Sized {
 
 
 /// Macro for implementing the FromGraphValue Trait for a int type
+/// Values outside of the range of the type fail with `GraphError::IntegerOverflow`.
+/// Note that redis graph itself only stores 64 bit integers and clamps larger literals
+/// like `RETURN 18446744073709551617` to `i64::MAX` before they reach the client
 macro_rules! from_graph_value_for_int {
     ( $t:ty ) => {
         impl FromGraphValue for $t {
             fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
                 match value {
-                    GraphValue::Integer(val) => <$t>::try_from(val).map_err(|_| create_rediserror(concat!("Could not convert to ", stringify!($t)))),
-                    _ => Err(create_rediserror(&format!(
-                        concat!("Cant convert {:?} to ", stringify!($t)),
-                        value
+                    GraphValue::Integer(val) => <$t>::try_from(val).map_err(|_| GraphError::IntegerOverflow.with_detail(format!(
+                        concat!("Value {} does not fit into ", stringify!($t)),
+                        val
                     ))),
+                    _ => Err(type_mismatch(&value, stringify!($t))),
                 }
             }
         }
@@ -282,17 +649,23 @@ macro_rules! from_graph_value_for_int {
 }
 
 /// Macro for implementing the FromGraphValue Trait for a float type
-impl FromGraphValue for f64 {
-    fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
-        match value {
-            GraphValue::Double(val) => Ok(val),
-            _ => Err(create_rediserror(&format!(
-                concat!("Cant convert {:?} to ", stringify!($t)),
-                value
-            ))),
+/// Integers are accepted as well as aggregations like count() return integers.
+/// The conversion is lossless for integers up to 2^53 (2^24 for f32)
+macro_rules! from_graph_value_for_float {
+    ( $t:ty ) => {
+        impl FromGraphValue for $t {
+            fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
+                match value {
+                    GraphValue::Double(val) => Ok(val as $t),
+                    GraphValue::Integer(val) => Ok(val as $t),
+                    _ => Err(type_mismatch(&value, stringify!($t))),
+                }
+            }
         }
-    }
+    };
 }
+
+apply_macro!(from_graph_value_for_float, f32, f64);
 apply_macro!(
     from_graph_value_for_int,
     i8,
@@ -309,10 +682,84 @@ impl FromGraphValue for bool {
     fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
         match value {
             GraphValue::Boolean(val) => Ok(val),
-            _ => Err(create_rediserror(&format!(
-                "Cant convert {:?} to bool",
-                value
-            ))),
+            _ => Err(type_mismatch(&value, "bool")),
+        }
+    }
+}
+
+/// Implements `TryFrom<GraphValue>` by delegating to the FromGraphValue impl of the type
+macro_rules! try_from_graph_value {
+    ($t:ty) => {
+        impl TryFrom<GraphValue> for $t {
+            type Error = RedisError;
+
+            fn try_from(value: GraphValue) -> RedisResult<Self> {
+                from_graph_value(value)
+            }
+        }
+    };
+}
+
+apply_macro!(try_from_graph_value, i64, f64, String, bool);
+
+/// Boolean which can also be parsed from the integers 0 and 1 for schemas that store flags as integers.
+/// Every other integer fails with `GraphError::TypeMismatch`
+///
+/// ## Example
+/// ```
+/// use redisgraphio::{from_graph_value, GraphValue, IntBool};
+///
+/// assert_eq!(from_graph_value::<IntBool>(GraphValue::Integer(1)).unwrap(), IntBool(true));
+/// assert!(from_graph_value::<IntBool>(GraphValue::Integer(2)).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct IntBool(pub bool);
+
+impl FromGraphValue for IntBool {
+    fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
+        match value {
+            GraphValue::Boolean(val) => Ok(IntBool(val)),
+            GraphValue::Integer(0) => Ok(IntBool(false)),
+            GraphValue::Integer(1) => Ok(IntBool(true)),
+            _ => Err(type_mismatch(&value, "IntBool, expected a bool or 0 or 1")),
+        }
+    }
+}
+
+impl From<IntBool> for bool {
+    fn from(value: IntBool) -> Self {
+        value.0
+    }
+}
+
+/// Raw bytes which are stored in the graph as a standard base64 encoded (with padding) string
+/// as redis graph has no binary value type.
+/// Use it as a parameter with `Parameter::from` and read it back with `from_graph_value`.
+/// It is a newtype instead of `Vec<u8>` itself because `FromGraphValue` and `Parameter::from`
+/// are already implemented for every `Vec<T>` as lists, which the `Vec<u8>` impls would conflict with
+///
+/// ## Example
+/// ```
+/// use redisgraphio::{from_graph_value, GraphBytes, GraphValue, Parameter};
+///
+/// let bytes = GraphBytes(vec![0, 159, 255]);
+/// assert_eq!(Parameter::from(bytes.clone()), Parameter::String("AJ//".to_string()));
+/// assert_eq!(from_graph_value::<GraphBytes>(GraphValue::String("AJ//".to_string())).unwrap(), bytes);
+/// ```
+#[cfg(feature = "base64")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct GraphBytes(pub Vec<u8>);
+
+#[cfg(feature = "base64")]
+impl FromGraphValue for GraphBytes {
+    fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
+        use base64::Engine;
+        match value {
+            GraphValue::String(encoded) => base64::engine::general_purpose::STANDARD
+                .decode(&encoded)
+                .map(GraphBytes)
+                .map_err(|err| GraphError::TypeMismatch.with_detail(format!("Cant decode {:?} as base64: {}", encoded, err))),
+            value => Err(type_mismatch(&value, "GraphBytes, expected a base64 encoded string")),
         }
     }
 }
@@ -330,10 +777,7 @@ impl<T: FromGraphValue> FromGraphValue for Vec<T> {
                 .into_iter()
                 .map(FromGraphValue::from_graph_value)
                 .collect::<RedisResult<Self>>()?),
-            _ => Err(create_rediserror(&format!(
-                "Cant convert {:?} to Vec",
-                value
-            ))),
+            _ => Err(type_mismatch(&value, "Vec")),
         }
     }
 }
@@ -342,22 +786,37 @@ impl FromGraphValue for GraphMap {
     fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
         match value {
             GraphValue::Map(map) => Ok(map),
-            _ => Err(create_rediserror(&format!(
-                "Cant convert {:?} to GraphMap",
-                value
-            ))),
+            _ => Err(type_mismatch(&value, "GraphMap")),
         }
     }
 }
 
+/// Macro for implementing the FromGraphValue Trait for a map type with String keys
+macro_rules! from_graph_value_for_map {
+    ( $t:ident ) => {
+        impl<T: FromGraphValue> FromGraphValue for $t<String, T> {
+            fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
+                match value {
+                    GraphValue::Map(map) => map
+                        .into_inner()
+                        .into_iter()
+                        .map(|(key, value)| Ok((key, from_graph_value(value)?)))
+                        .collect(),
+                    _ => Err(type_mismatch(&value, stringify!($t))),
+                }
+            }
+        }
+    };
+}
+
+from_graph_value_for_map!(HashMap);
+from_graph_value_for_map!(BTreeMap);
+
 impl FromGraphValue for GraphPath {
     fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
         match value {
             GraphValue::Path(path) => Ok(path),
-            _ => Err(create_rediserror(&format!(
-                "Cant convert {:?} to GraphPath",
-                value
-            ))),
+            _ => Err(type_mismatch(&value, "GraphPath")),
         }
     }
 }
@@ -366,10 +825,17 @@ impl FromGraphValue for GeoPoint {
     fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
         match value {
             GraphValue::Point(point) => Ok(point),
-            _ => Err(create_rediserror(&format!(
-                "Cant convert {:?} to GeoPoint",
-                value
-            ))),
+            _ => Err(type_mismatch(&value, "GeoPoint")),
+        }
+    }
+}
+
+/// Converts a point into `[latitude, longitude]`
+impl FromGraphValue for [f64; 2] {
+    fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
+        match value {
+            GraphValue::Point(point) => Ok([point.latitude, point.longitude]),
+            _ => Err(type_mismatch(&value, "[f64; 2]")),
         }
     }
 }
@@ -378,10 +844,7 @@ impl FromGraphValue for Node {
     fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
         match value {
             GraphValue::Node(node) => Ok(node),
-            _ => Err(create_rediserror(&format!(
-                "Cant convert {:?} to Node",
-                value
-            ))),
+            _ => Err(type_mismatch(&value, "Node")),
         }
     }
 }
@@ -390,10 +853,7 @@ impl FromGraphValue for Relationship {
     fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
         match value {
             GraphValue::Relation(rel) => Ok(rel),
-            _ => Err(create_rediserror(&format!(
-                "Cant convert {:?} to Relationship",
-                value
-            ))),
+            _ => Err(type_mismatch(&value, "Relationship")),
         }
     }
 }
@@ -407,6 +867,21 @@ impl<T: FromGraphValue> FromGraphValue for Option<T> {
     }
 }
 
+/// Implements FromGraphValue for smart pointers by converting the inner value
+macro_rules! from_graph_value_for_pointer {
+    ($t:ident) => {
+        impl<T: FromGraphValue> FromGraphValue for $t<T> {
+            fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
+                from_graph_value(value).map($t::new)
+            }
+        }
+    };
+}
+
+from_graph_value_for_pointer!(Box);
+from_graph_value_for_pointer!(Rc);
+from_graph_value_for_pointer!(Arc);
+
 impl FromGraphValue for GraphValue {
     fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
         Ok(value)
@@ -417,11 +892,188 @@ impl FromGraphValue for String {
     fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
         match value {
             GraphValue::String(s) => Ok(s.to_string()),
-            _ => Err(create_rediserror(&format!(
-                "Cant convert {:?} to String",
-                value
-            ))),
+            _ => Err(type_mismatch(&value, "String")),
+        }
+    }
+}
+
+/// Borrowing counterpart of [`FromGraphValue`] which converts a reference to a value
+/// without cloning e.g. into a `&str` that points into the parsed response
+///
+/// ## Example
+/// ```
+/// use redisgraphio::{from_graph_value_ref, GraphValue};
+///
+/// let value = GraphValue::String("Hello".to_string());
+/// let s: &str = from_graph_value_ref(&value).unwrap();
+/// assert_eq!(s, "Hello");
+/// ```
+pub trait FromGraphValueRef<'a>: Sized {
+    /// Converts the borrowed GraphValue to the implementing Type
+    fn from_graph_value_ref(value: &'a GraphValue) -> RedisResult<Self>;
+}
+
+/// Implements FromGraphValueRef for a type returned by one of the borrowing accessors of GraphValue
+macro_rules! from_graph_value_ref_via_accessor {
+    ($($t:ty => $accessor:ident, $name:literal;)*) => {
+        $(
+            impl<'a> FromGraphValueRef<'a> for $t {
+                fn from_graph_value_ref(value: &'a GraphValue) -> RedisResult<Self> {
+                    value.$accessor().ok_or_else(|| type_mismatch(value, $name))
+                }
+            }
+        )*
+    };
+}
+
+from_graph_value_ref_via_accessor! {
+    &'a str => as_str, "&str";
+    &'a [GraphValue] => as_array, "&[GraphValue]";
+    &'a GraphMap => as_map, "&GraphMap";
+    &'a Node => as_node, "&Node";
+    &'a Relationship => as_relationship, "&Relationship";
+    &'a GraphPath => as_path, "&GraphPath";
+    &'a GeoPoint => as_point, "&GeoPoint";
+    i64 => as_integer, "i64";
+    f64 => as_double, "f64";
+    bool => as_bool, "bool";
+}
+
+impl<'a> FromGraphValueRef<'a> for &'a GraphValue {
+    fn from_graph_value_ref(value: &'a GraphValue) -> RedisResult<Self> {
+        Ok(value)
+    }
+}
+
+impl<'a> FromGraphValueRef<'a> for Cow<'a, str> {
+    fn from_graph_value_ref(value: &'a GraphValue) -> RedisResult<Self> {
+        <&str>::from_graph_value_ref(value).map(Cow::Borrowed)
+    }
+}
+
+impl<'a, T: FromGraphValueRef<'a>> FromGraphValueRef<'a> for Option<T> {
+    fn from_graph_value_ref(value: &'a GraphValue) -> RedisResult<Self> {
+        match value {
+            GraphValue::Null => Ok(None),
+            value => T::from_graph_value_ref(value).map(Some),
+        }
+    }
+}
+
+/// Converts any value except `Unknown` into json.
+/// Nodes, relationships, paths and points become objects of their fields
+/// and properties are keyed by their property key id
+#[cfg(feature = "serde_json")]
+impl FromGraphValue for serde_json::Value {
+    fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
+        use serde_json::{json, Map, Value as Json};
+
+        fn properties(properties: IndexMap<i64, GraphValue>) -> RedisResult<Json> {
+            properties
+                .into_iter()
+                .map(|(id, value)| Ok((id.to_string(), from_graph_value(value)?)))
+                .collect::<RedisResult<Map<String, Json>>>()
+                .map(Json::Object)
+        }
+        fn node(node: Node) -> RedisResult<Json> {
+            Ok(json!({
+                "id": node.id,
+                "label_ids": node.label_ids,
+                "properties": properties(node.properties)?,
+            }))
         }
+        fn relationship(rel: Relationship) -> RedisResult<Json> {
+            Ok(json!({
+                "id": rel.id,
+                "label_id": rel.label_id,
+                "src": rel.src,
+                "dest": rel.dest,
+                "properties": properties(rel.properties)?,
+            }))
+        }
+
+        Ok(match value {
+            GraphValue::Unknown(_) => {
+                return Err(type_mismatch(&value, "serde_json::Value"))
+            }
+            GraphValue::Map(map) => Json::Object(
+                map.into_inner()
+                    .into_iter()
+                    .map(|(key, value)| Ok((key, from_graph_value(value)?)))
+                    .collect::<RedisResult<_>>()?,
+            ),
+            GraphValue::Point(point) => json!({
+                "latitude": point.latitude,
+                "longitude": point.longitude,
+            }),
+            GraphValue::Path(path) => json!({
+                "nodes": path.nodes.into_iter().map(node).collect::<RedisResult<Vec<_>>>()?,
+                "relationships": path.relationships.into_iter().map(relationship).collect::<RedisResult<Vec<_>>>()?,
+            }),
+            GraphValue::Node(n) => node(n)?,
+            GraphValue::Relation(rel) => relationship(rel)?,
+            GraphValue::Array(values) => Json::Array(
+                values.into_iter().map(from_graph_value).collect::<RedisResult<_>>()?,
+            ),
+            GraphValue::Integer(int) => Json::from(int),
+            // NaN and infinity are not representable in json and become null
+            GraphValue::Double(double) => Json::from(double),
+            GraphValue::String(string) => Json::String(string),
+            GraphValue::Boolean(boolean) => Json::Bool(boolean),
+            GraphValue::Null => Json::Null,
+        })
+    }
+}
+
+/// Parses a `String` in any format accepted by `Uuid::parse_str` e.g. hyphenated
+#[cfg(feature = "uuid")]
+impl FromGraphValue for uuid::Uuid {
+    fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
+        match value {
+            GraphValue::String(s) => uuid::Uuid::parse_str(&s)
+                .map_err(|err| GraphError::TypeMismatch.with_detail(format!("Cant parse {:?} as Uuid: {}", s, err))),
+            value => Err(type_mismatch(&value, "Uuid")),
+        }
+    }
+}
+
+/// Interprets an `Integer` as milliseconds, negative values and every other type fail
+impl FromGraphValue for std::time::Duration {
+    fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
+        match value {
+            GraphValue::Integer(millis) => u64::try_from(millis)
+                .map(std::time::Duration::from_millis)
+                .map_err(|_| GraphError::IntegerOverflow.with_detail(format!("Negative duration of {} milliseconds", millis))),
+            value => Err(type_mismatch(&value, "Duration, expected milliseconds")),
+        }
+    }
+}
+
+/// Interprets an `Integer` as seconds and a `Double` as fractional seconds since the unix epoch.
+/// Values stored in milliseconds can be converted with `i64` and `DateTime::from_timestamp_millis`
+#[cfg(feature = "chrono")]
+impl FromGraphValue for chrono::DateTime<chrono::Utc> {
+    fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
+        let (secs, nanos) = match value {
+            GraphValue::Integer(secs) => (secs, 0),
+            GraphValue::Double(secs) if secs.is_finite() => {
+                (secs.floor() as i64, ((secs - secs.floor()) * 1e9) as u32)
+            }
+            value => {
+                return Err(type_mismatch(&value, "DateTime, expected seconds since the unix epoch"))
+            }
+        };
+        chrono::DateTime::from_timestamp(secs, nanos).ok_or_else(|| {
+            GraphError::TypeMismatch.with_detail(format!("Timestamp {} is out of range for DateTime", secs))
+        })
+    }
+}
+
+/// Interprets the value like the conversion to `DateTime<Utc>`
+#[cfg(feature = "chrono")]
+impl FromGraphValue for chrono::NaiveDateTime {
+    fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
+        from_graph_value::<chrono::DateTime<chrono::Utc>>(value).map(|time| time.naive_utc())
     }
 }
 
@@ -441,7 +1093,10 @@ macro_rules! from_graph_value_for_tuple {
                         let mut n = 0;
                         $(let $name = (); n += 1;)*
                         if items.len() != n {
-                            return Err(create_rediserror(&format!("Wrong length to create Tuple {} from {:?}", std::any::type_name::<Self>(), &items)))
+                            return Err(GraphError::LengthMismatch.with_detail(format!(
+                                "Wrong length to create Tuple {}: expected {} but got {} from {:?}",
+                                std::any::type_name::<Self>(), n, items.len(), &items
+                            )))
                         }
 
                         Ok(($({
@@ -449,7 +1104,7 @@ macro_rules! from_graph_value_for_tuple {
                             FromGraphValue::from_graph_value(items.remove(0))?
                         },)*))
                     }
-                    _ => Err(create_rediserror(&format!("Can not create Tuple from {:?}", v)))
+                    _ => Err(type_mismatch(&v, "Tuple"))
                 }
             }
         }
@@ -464,19 +1119,19 @@ macro_rules! from_graph_value_for_tuple_peel {
     ($name:ident, $($other:ident,)*) => (from_graph_value_for_tuple!($($other,)*);)
 }
 
-from_graph_value_for_tuple! { T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, }
+from_graph_value_for_tuple! { T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, }
 
 impl FromRedisValue for GraphValue {
     fn from_redis_value(v: &Value) -> RedisResult<Self> {
         match v {
             Value::Bulk(data) if data.len() == 2 => match &data[0] {
                 Value::Int(type_) => convert_to_graphvalue(*type_, &data[1]),
-                value => Err(create_rediserror(&format!(
+                value => Err(GraphError::InvalidResponse.with_detail(format!(
                     "Couldnt convert {:?} to GraphValue",
                     value
                 ))),
             },
-            value => Err(create_rediserror(&format!(
+            value => Err(GraphError::InvalidResponse.with_detail(format!(
                 "Couldnt convert {:?} to GraphValue",
                 value
             ))),
@@ -496,7 +1151,7 @@ impl FromRedisValue for GraphPath {
 
 impl FromRedisValue for GeoPoint {
     fn from_redis_value(v: &Value) -> RedisResult<Self> {
-        let (latitude, longitude): (f32, f32) = from_redis_value(v)?;
+        let (latitude, longitude): (f64, f64) = from_redis_value(v)?;
         Ok(GeoPoint {
             latitude,
             longitude,
@@ -511,7 +1166,7 @@ impl FromRedisValue for GraphMap {
                 let temp: Vec<(String, GraphValue)> = FromRedisValue::from_redis_values(values)?;
                 Ok(GraphMap(temp.into_iter().collect()))
             }
-            value => Err(create_rediserror(&format!(
+            value => Err(GraphError::InvalidResponse.with_detail(format!(
                 "Couldnt convert {:?} to GraphMap",
                 value
             ))),
@@ -527,7 +1182,7 @@ impl FromRedisValue for Node {
                 from_redis_value(&values[1])?,
                 parse_properties(&values[2])?,
             )),
-            val => Err(create_rediserror(&format!(
+            val => Err(GraphError::InvalidResponse.with_detail(format!(
                 "Couldnt convert {:?} to Node",
                 val
             ))),
@@ -535,6 +1190,38 @@ impl FromRedisValue for Node {
     }
 }
 
+/// Rows of `graph_query_node_ids` which only reads the id and label ids of a single node column
+pub(crate) struct NodeIdRows(pub(crate) Vec<(i64, Vec<i64>)>);
+
+impl FromRedisValue for NodeIdRows {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let rows = match v {
+            Value::Bulk(values) => match values.as_slice() {
+                [_] => return Ok(NodeIdRows(vec![])),
+                [_, Value::Bulk(rows), _] => rows,
+                _ => return Err(GraphError::InvalidResponse.with_detail(format!("Couldnt read node ids from {:?}", v))),
+            },
+            _ => return Err(GraphError::InvalidResponse.with_detail(format!("Couldnt read node ids from {:?}", v))),
+        };
+        rows.iter()
+            .map(|row| match row {
+                Value::Bulk(columns) => match columns.as_slice() {
+                    [Value::Bulk(cell)] => match cell.as_slice() {
+                        [Value::Int(types::VALUE_NODE), Value::Bulk(node)] if node.len() == 3 => {
+                            Ok((from_redis_value(&node[0])?, from_redis_value(&node[1])?))
+                        }
+                        _ => Err(GraphError::TypeMismatch.with_detail(format!("Expected a node but got {:?}", cell))),
+                    },
+                    _ => Err(GraphError::LengthMismatch.with_detail(format!(
+                        "Expected a single node column but got {} columns", columns.len()
+                    ))),
+                },
+                row => Err(GraphError::InvalidResponse.with_detail(format!("Couldnt read node ids from {:?}", row))),
+            })
+            .collect::<RedisResult<_>>()
+            .map(NodeIdRows)
+    }
+}
 
 impl FromRedisValue for Relationship {
     fn from_redis_value(v: &Value) -> RedisResult<Self> {
@@ -546,7 +1233,7 @@ impl FromRedisValue for Relationship {
                 from_redis_value(&values[3])?,
                 parse_properties(&values[4])?,
             )),
-            val => Err(create_rediserror(&format!(
+            val => Err(GraphError::InvalidResponse.with_detail(format!(
                 "Couldnt convert {:?} to Relationship",
                 val
             ))),
@@ -572,6 +1259,7 @@ fn parse_properties(value: &Value) -> RedisResult<IndexMap<i64, GraphValue>> {
         .collect()
 }
 
+#[allow(clippy::wildcard_in_or_patterns)]
 fn convert_to_graphvalue(type_: i64, val: &Value) -> RedisResult<GraphValue> {
     use types::*;
     match type_ {
@@ -581,7 +1269,18 @@ fn convert_to_graphvalue(type_: i64, val: &Value) -> RedisResult<GraphValue> {
         VALUE_MAP => Ok(GraphValue::Map(from_redis_value(val)?)),
         VALUE_POINT => Ok(GraphValue::Point(from_redis_value(val)?)),
         VALUE_NULL => Ok(GraphValue::Null),
-        VALUE_DOUBLE => Ok(GraphValue::Double(from_redis_value(val)?)),
+        VALUE_DOUBLE => Ok(GraphValue::Double({
+            // Redis graph formats special doubles like C does e.g. "inf" or "-nan"
+            match val {
+                Value::Data(bytes) => match bytes.as_slice() {
+                    b"inf" | b"+inf" => f64::INFINITY,
+                    b"-inf" => f64::NEG_INFINITY,
+                    b"nan" | b"-nan" => f64::NAN,
+                    _ => from_redis_value(val)?,
+                },
+                _ => from_redis_value(val)?,
+            }
+        })),
         VALUE_INTEGER => Ok(GraphValue::Integer(from_redis_value(val)?)),
         VALUE_ARRAY => Ok(GraphValue::Array(from_redis_value(val)?)),
         VALUE_STRING => Ok(GraphValue::String(from_redis_value(val)?)),
@@ -591,7 +1290,7 @@ fn convert_to_graphvalue(type_: i64, val: &Value) -> RedisResult<GraphValue> {
                 "true" => true,
                 "false" => false,
                 _ => {
-                    return Err(create_rediserror(&format!(
+                    return Err(GraphError::InvalidResponse.with_detail(format!(
                         "Cant convert {:?} to bool",
                         val
                     )))
@@ -601,3 +1300,44 @@ fn convert_to_graphvalue(type_: i64, val: &Value) -> RedisResult<GraphValue> {
         VALUE_UNKNOWN | _ => Ok(GraphValue::Unknown(val.to_owned())),
     }
 }
+
+/// Fails with `GraphError::UnknownValueType` if the type id of the typed value or of any value nested in it
+/// is unknown to this client or the unknown type id 0. Malformed values are left to the regular parsing to report
+pub(crate) fn check_value_types(v: &Value) -> RedisResult<()> {
+    match v {
+        Value::Bulk(data) if data.len() == 2 => match &data[0] {
+            Value::Int(type_) => check_type_id(*type_, &data[1]),
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+fn check_type_id(type_: i64, val: &Value) -> RedisResult<()> {
+    use types::*;
+    match (type_, val) {
+        (VALUE_ARRAY | VALUE_PATH, Value::Bulk(values)) => values.iter().try_for_each(check_value_types),
+        // Maps alternate between the keys and the typed values
+        (VALUE_MAP, Value::Bulk(values)) => values.iter().skip(1).step_by(2).try_for_each(check_value_types),
+        (VALUE_NODE, Value::Bulk(values)) if values.len() == 3 => check_property_types(&values[2]),
+        (VALUE_EDGE, Value::Bulk(values)) if values.len() == 5 => check_property_types(&values[4]),
+        (VALUE_NULL..=VALUE_POINT, _) => Ok(()),
+        _ => Err(GraphError::UnknownValueType.with_detail(format!(
+            "Unknown value type id {} for {:?}",
+            type_, val
+        ))),
+    }
+}
+
+fn check_property_types(value: &Value) -> RedisResult<()> {
+    match value {
+        Value::Bulk(properties) => properties.iter().try_for_each(|property| match property {
+            Value::Bulk(property) => match property.as_slice() {
+                [_, Value::Int(type_), value] => check_type_id(*type_, value),
+                _ => Ok(()),
+            },
+            _ => Ok(()),
+        }),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,80 @@
+use redis::{pipe, ConnectionLike, RedisResult, Value};
+
+use crate::{FromGraphValue, GraphQuery, GraphResponse};
+
+/// Sends multiple graph queries in a single round trip.
+/// Every query is parsed into a `GraphResponse<T>` of the same return type
+///
+/// ## Example
+/// ```no_run
+/// # use redisgraphio::*;
+/// # fn example(con: &mut redis::Connection) -> redis::RedisResult<()> {
+/// let responses: Vec<GraphResponse<(i64,)>> = GraphPipeline::new()
+///     .query("graph", query!("CREATE (n:User) RETURN id(n)"))
+///     .query("graph", query!("MATCH (n:User) RETURN count(n)", true))
+///     .execute(con)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct GraphPipeline {
+    queries: Vec<(String, GraphQuery)>,
+}
+
+impl GraphPipeline {
+    /// Creates an empty pipeline
+    pub fn new() -> GraphPipeline {
+        GraphPipeline::default()
+    }
+
+    /// Adds a query against the given graph to the pipeline
+    pub fn query<Q: Into<GraphQuery>>(&mut self, graph: &str, query: Q) -> &mut GraphPipeline {
+        self.queries.push((graph.to_owned(), query.into()));
+        self
+    }
+
+    /// Number of queries in the pipeline
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    /// Whether the pipeline contains no queries
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+
+    fn build(&self) -> RedisResult<redis::Pipeline> {
+        let mut pipeline = pipe();
+        for (graph, query) in self.queries.iter() {
+            pipeline.add_command(query.to_cmd(graph)?);
+        }
+        Ok(pipeline)
+    }
+
+    /// Sends all queries and returns their responses in the order they were added
+    pub fn execute<C, T>(&self, con: &mut C) -> RedisResult<Vec<GraphResponse<T>>>
+    where
+        C: ConnectionLike,
+        T: FromGraphValue,
+    {
+        if self.is_empty() {
+            return Ok(vec![]);
+        }
+        let values: Vec<Value> = self.build()?.query(con)?;
+        values.iter().map(GraphResponse::parse_response).collect()
+    }
+
+    /// Sends all queries asynchronously and returns their responses in the order they were added
+    #[cfg(any(feature = "tokio-comp", feature = "async-std-comp"))]
+    pub async fn execute_async<C, T>(&self, con: &mut C) -> RedisResult<Vec<GraphResponse<T>>>
+    where
+        C: redis::aio::ConnectionLike,
+        T: FromGraphValue,
+    {
+        if self.is_empty() {
+            return Ok(vec![]);
+        }
+        let values: Vec<Value> = self.build()?.query_async(con).await?;
+        values.iter().map(GraphResponse::parse_response).collect()
+    }
+}
@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+use redis::{ErrorKind, RedisError};
+
+/// Controls how often and how fast `graph_query_with_retry` retries a query.
+/// Only transient errors like `BUSY`, `LOADING` or `TRYAGAIN` are retried,
+/// errors caused by the query or the conversion of its result are returned immediately.
+/// The `Query timed out` reply of the server is only retried for read only queries, as the server
+/// may already have executed parts of a write query and sending it again would apply them twice.
+/// Client side IO timeouts are never retried: the reply of the timed out attempt is still pending on the
+/// connection and would be read as the reply of the retry, so the caller has to reconnect instead
+///
+/// ## Example
+/// ```
+/// use std::time::Duration;
+/// use redisgraphio::RetryPolicy;
+///
+/// let policy = RetryPolicy::new(3).backoff(Duration::from_millis(50));
+/// assert_eq!(policy.delay(2), Duration::from_millis(100));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Sends the query at most `max_attempts` times without waiting between attempts
+    pub fn new(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            backoff: Duration::ZERO,
+        }
+    }
+
+    /// Waits `backoff` before the first retry and doubles the wait for each following retry
+    pub fn backoff(mut self, backoff: Duration) -> RetryPolicy {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Maximum number of times the query is sent
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Time to wait before the given retry where the first retry is 1
+    pub fn delay(&self, retry: u32) -> Duration {
+        self.backoff.saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+    }
+
+    /// Whether the error is transient so sending the query again on the same connection can succeed.
+    /// Query timeouts of the server are only retryable if the query is read only
+    pub fn is_retryable(err: &RedisError, read_only: bool) -> bool {
+        matches!(err.kind(), ErrorKind::BusyLoadingError | ErrorKind::TryAgain)
+            || err.code() == Some("BUSY")
+            || (read_only && RetryPolicy::is_query_timeout(err))
+    }
+
+    /// Whether the server aborted the query because it exceeded its timeout.
+    /// Redis graph replies with `-Query timed out` which redis parses into the code `Query` and the detail `timed out`
+    fn is_query_timeout(err: &RedisError) -> bool {
+        (err.code() == Some("Query") && err.detail() == Some("timed out"))
+            || (err.kind() == ErrorKind::ResponseError
+                && err.detail().is_some_and(|detail| detail.contains("Query timed out")))
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Sends the query once without retrying
+    fn default() -> Self {
+        RetryPolicy::new(1)
+    }
+}
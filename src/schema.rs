@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use redis::RedisResult;
+
+use crate::GraphCommands;
+
+#[cfg(any(feature = "tokio-comp", feature = "async-std-comp"))]
+use crate::AsyncGraphCommands;
+
+/// Cache for the label, property key and relationship type names of a graph.
+/// Nodes and relationships only contain the ids of these names so the mapping is needed to resolve them.
+/// The ids can shift when the graph changes so call `refresh` to fetch the current mapping again.
+///
+/// The names are fetched eagerly: `load` and `refresh` always query all three of them,
+/// even if only one kind of name is looked up later.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphSchema {
+    graph: String,
+    labels: Vec<String>,
+    property_keys: Vec<String>,
+    relationship_types: Vec<String>,
+    label_ids: HashMap<String, i64>,
+    property_key_ids: HashMap<String, i64>,
+    relationship_type_ids: HashMap<String, i64>,
+}
+
+impl GraphSchema {
+    /// Fetches the labels, property keys and relationship types of the graph with one query each
+    pub fn load<C: GraphCommands>(con: &mut C, graph: &str) -> RedisResult<GraphSchema> {
+        let mut schema = GraphSchema {
+            graph: graph.to_owned(),
+            labels: vec![],
+            property_keys: vec![],
+            relationship_types: vec![],
+            label_ids: HashMap::new(),
+            property_key_ids: HashMap::new(),
+            relationship_type_ids: HashMap::new(),
+        };
+        schema.refresh(con)?;
+        Ok(schema)
+    }
+
+    /// Fetches the labels, property keys and relationship types of the graph again
+    pub fn refresh<C: GraphCommands>(&mut self, con: &mut C) -> RedisResult<()> {
+        self.labels = con.labels(&self.graph)?;
+        self.property_keys = con.property_keys(&self.graph)?;
+        self.relationship_types = con.relationship_types(&self.graph)?;
+        self.build_reverse_lookups();
+        Ok(())
+    }
+
+    /// Fetches the labels, property keys and relationship types of the graph asynchronously with one query each
+    #[cfg(any(feature = "tokio-comp", feature = "async-std-comp"))]
+    pub async fn load_async<C: AsyncGraphCommands>(con: &mut C, graph: &str) -> RedisResult<GraphSchema> {
+        let mut schema = GraphSchema {
+            graph: graph.to_owned(),
+            labels: vec![],
+            property_keys: vec![],
+            relationship_types: vec![],
+            label_ids: HashMap::new(),
+            property_key_ids: HashMap::new(),
+            relationship_type_ids: HashMap::new(),
+        };
+        schema.refresh_async(con).await?;
+        Ok(schema)
+    }
+
+    /// Fetches the labels, property keys and relationship types of the graph again asynchronously
+    #[cfg(any(feature = "tokio-comp", feature = "async-std-comp"))]
+    pub async fn refresh_async<C: AsyncGraphCommands>(&mut self, con: &mut C) -> RedisResult<()> {
+        self.labels = con.labels(&self.graph).await?;
+        self.property_keys = con.property_keys(&self.graph).await?;
+        self.relationship_types = con.relationship_types(&self.graph).await?;
+        self.build_reverse_lookups();
+        Ok(())
+    }
+
+    fn build_reverse_lookups(&mut self) {
+        self.label_ids = reverse(&self.labels);
+        self.property_key_ids = reverse(&self.property_keys);
+        self.relationship_type_ids = reverse(&self.relationship_types);
+    }
+
+    /// Name of the graph this schema belongs to
+    pub fn graph(&self) -> &str {
+        &self.graph
+    }
+
+    /// Label names where the index is the label id
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// Property key names where the index is the property key id
+    pub fn property_keys(&self) -> &[String] {
+        &self.property_keys
+    }
+
+    /// Relationship type names where the index is the relationship type id
+    pub fn relationship_types(&self) -> &[String] {
+        &self.relationship_types
+    }
+
+    /// Name of the label with the given id
+    pub fn label_name(&self, id: i64) -> Option<&str> {
+        lookup(&self.labels, id)
+    }
+
+    /// Name of the property key with the given id
+    pub fn property_name(&self, id: i64) -> Option<&str> {
+        lookup(&self.property_keys, id)
+    }
+
+    /// Name of the relationship type with the given id
+    pub fn relationship_name(&self, id: i64) -> Option<&str> {
+        lookup(&self.relationship_types, id)
+    }
+
+    /// Id of the label with the given name
+    pub fn label_id(&self, name: &str) -> Option<i64> {
+        self.label_ids.get(name).copied()
+    }
+
+    /// Id of the property key with the given name
+    pub fn property_key_id(&self, name: &str) -> Option<i64> {
+        self.property_key_ids.get(name).copied()
+    }
+
+    /// Id of the relationship type with the given name
+    pub fn relationship_type_id(&self, name: &str) -> Option<i64> {
+        self.relationship_type_ids.get(name).copied()
+    }
+}
+
+fn lookup(names: &[String], id: i64) -> Option<&str> {
+    names.get(usize::try_from(id).ok()?).map(String::as_str)
+}
+
+fn reverse(names: &[String]) -> HashMap<String, i64> {
+    names.iter().enumerate().map(|(id, name)| (name.clone(), id as i64)).collect()
+}
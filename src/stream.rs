@@ -0,0 +1,82 @@
+use std::marker::PhantomData;
+
+use redis::{from_redis_value, FromRedisValue, RedisResult, Value};
+
+use crate::{from_graph_value, ColumnType, FromGraphValue, GraphError, GraphResponse, GraphValue};
+
+/// Lazily parsed response of a graph query.
+/// Unlike [`GraphResponse`] the rows are only converted to `T` once they are requested,
+/// so only the raw reply and not every parsed row has to be kept in memory.
+///
+/// Rows can be consumed with `next_row` or with the `tokio-comp` or `async-std-comp` feature as a `Stream`
+#[derive(Debug)]
+pub struct GraphRowStream<T = GraphValue> {
+    header: Vec<(ColumnType, String)>,
+    rows: std::vec::IntoIter<Value>,
+    statistics: Vec<String>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: FromGraphValue> GraphRowStream<T> {
+    /// Types and names of the returned columns
+    pub fn header(&self) -> &[(ColumnType, String)] {
+        &self.header
+    }
+
+    /// Statistics of the query e.g. "Cached execution: 1"
+    pub fn statistics(&self) -> &[String] {
+        &self.statistics
+    }
+
+    /// Number of rows which have not been parsed yet
+    pub fn remaining(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Parses the next row or returns `None` if all rows were consumed
+    pub fn next_row(&mut self) -> Option<RedisResult<T>> {
+        self.rows.next().map(|row| from_graph_value(GraphValue::Array(from_redis_value(&row)?)))
+    }
+}
+
+impl<T: FromGraphValue> FromRedisValue for GraphRowStream<T> {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let (header, rows, statistics): (_, Vec<Value>, _) = match v {
+            Value::Bulk(values) => match values.as_slice() {
+                [statistics] => (vec![], vec![], from_redis_value(statistics)?),
+                [header, rows, statistics] => (
+                    <GraphResponse>::parse_header(from_redis_value(header)?)?,
+                    from_redis_value(rows)?,
+                    from_redis_value(statistics)?,
+                ),
+                values => return Err(GraphError::InvalidResponse.with_detail(format!(
+                    "Can't parse response of length {} to GraphRowStream",
+                    values.len()
+                ))),
+            },
+            _ => return Err(GraphError::InvalidResponse.with_detail("Invalid Response from Redis")),
+        };
+        Ok(GraphRowStream {
+            header,
+            rows: rows.into_iter(),
+            statistics,
+            _marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(any(feature = "tokio-comp", feature = "async-std-comp"))]
+impl<T: FromGraphValue> futures_core::Stream for GraphRowStream<T> {
+    type Item = RedisResult<T>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        std::task::Poll::Ready(self.get_mut().next_row())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining(), Some(self.remaining()))
+    }
+}
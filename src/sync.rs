@@ -1,5 +1,6 @@
-use crate::{GraphResponse, FromGraphValue, GraphQuery, query};
-use redis::{cmd, ConnectionLike, RedisResult};
+use crate::{helpers::cypher_identifier, parse::NodeIdRows, GraphResponse, FromGraphValue, GraphError, GraphIndex, GraphQuery, GraphRowStream, GraphStatistics, GraphValue, Parameter, ProfileStep, RetryPolicy, SlowlogEntry, query};
+use redis::{cmd, from_redis_value, ConnectionLike, RedisResult, Value};
+use std::collections::HashMap;
 
 /// Implements redis graph related commands for an synchronous connection
 pub trait GraphCommands: ConnectionLike + Sized {
@@ -8,29 +9,250 @@ pub trait GraphCommands: ConnectionLike + Sized {
         &mut self,
         graph: &str,
         query: Q,
+    ) -> RedisResult<GraphResponse<RT>> where Q: Into<GraphQuery>, RT: FromGraphValue {
+        query.into().to_cmd(graph)?.query(self)
+    }
+
+    /// Send a graph query and send it again on transient errors like `BUSY` as configured by the policy.
+    /// Query timeouts of the server are only retried if the query is read only and client side IO timeouts never, see [`RetryPolicy`].
+    /// Returns the last error if every attempt failed
+    fn graph_query_with_retry<Q, RT>(
+        &mut self,
+        graph: &str,
+        query: Q,
+        policy: &RetryPolicy,
     ) -> RedisResult<GraphResponse<RT>> where Q: Into<GraphQuery>, RT: FromGraphValue {
         let query = query.into();
-        cmd(query.read_type())
+        let cmd = query.to_cmd(graph)?;
+        let mut attempt = 1;
+        loop {
+            match cmd.query(self) {
+                Err(err) if attempt < policy.max_attempts() && RetryPolicy::is_retryable(&err, query.read_only) => {
+                    std::thread::sleep(policy.delay(attempt));
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Send a graph query with additional arguments after `--compact` e.g. flags this crate does not support yet.
+    /// The query string is sent as is without any parameters
+    fn graph_query_raw<RT: FromGraphValue>(
+        &mut self,
+        graph: &str,
+        query: &str,
+        extra_args: &[&str],
+    ) -> RedisResult<GraphResponse<RT>> {
+        cmd("GRAPH.QUERY")
             .arg(graph)
-            .arg(query.construct_query())
+            .arg(query)
             .arg("--compact")
+            .arg(extra_args)
             .query(self)
     }
 
-    /// Send a graph query and ignore the result data
+    /// Send a graph query and parse the rows as `GraphValue`s without failing on single cells.
+    /// Cells which can not be parsed are returned as `GraphValue::Unknown` so the rest of the row can still be inspected
+    fn graph_query_lenient<Q>(
+        &mut self,
+        graph: &str,
+        query: Q,
+    ) -> RedisResult<GraphResponse<GraphValue>> where Q: Into<GraphQuery> {
+        let value: Value = query.into().to_cmd(graph)?.query(self)?;
+        GraphResponse::parse_lenient(&value)
+    }
+
+    /// Send a graph query like `graph_query` but fail with `GraphError::UnknownValueType` instead of
+    /// returning `GraphValue::Unknown` for values with a type id this client does not know about
+    fn graph_query_strict<Q, RT>(
+        &mut self,
+        graph: &str,
+        query: Q,
+    ) -> RedisResult<GraphResponse<RT>> where Q: Into<GraphQuery>, RT: FromGraphValue {
+        let value: Value = query.into().to_cmd(graph)?.query(self)?;
+        GraphResponse::parse_response_strict(&value)
+    }
+
+    /// Send a graph query and return the first column of the first row or `None` if no rows were returned.
+    /// Fails if the rows have more than one column
+    fn graph_query_scalar<Q, T>(
+        &mut self,
+        graph: &str,
+        query: Q,
+    ) -> RedisResult<Option<T>> where Q: Into<GraphQuery>, T: FromGraphValue {
+        let data: Vec<(T,)> = self.graph_query(graph, query)?.data;
+        Ok(data.into_iter().next().map(|(value,)| value))
+    }
+
+    /// Send a graph query and collect the parsed rows into any collection e.g. a `HashSet`
+    /// without building a `Vec` of them first
+    fn graph_query_collect<Q, RT, C>(
+        &mut self,
+        graph: &str,
+        query: Q,
+    ) -> RedisResult<C> where Q: Into<GraphQuery>, RT: FromGraphValue, C: FromIterator<RT> {
+        let mut stream: GraphRowStream<RT> = query.into().to_cmd(graph)?.query(self)?;
+        std::iter::from_fn(|| stream.next_row()).collect()
+    }
+
+    /// Send a graph query which returns a single node column and return only the id and label ids of each node.
+    /// The properties of the nodes are skipped instead of being parsed
+    fn graph_query_node_ids<Q>(
+        &mut self,
+        graph: &str,
+        query: Q,
+    ) -> RedisResult<Vec<(i64, Vec<i64>)>> where Q: Into<GraphQuery> {
+        let rows: NodeIdRows = query.into().to_cmd(graph)?.query(self)?;
+        Ok(rows.0)
+    }
+
+    /// Send a graph query and return every row as a map from the column names to the returned values
+    fn graph_query_maps<Q>(
+        &mut self,
+        graph: &str,
+        query: Q,
+    ) -> RedisResult<Vec<HashMap<String, GraphValue>>> where Q: Into<GraphQuery> {
+        Ok(rows_to_maps(self.graph_query(graph, query)?))
+    }
+
+    /// Send a graph query and return the only row or `None` if no rows were returned.
+    /// Fails if more than one row was returned
+    fn graph_query_one<Q, RT>(
+        &mut self,
+        graph: &str,
+        query: Q,
+    ) -> RedisResult<Option<RT>> where Q: Into<GraphQuery>, RT: FromGraphValue {
+        let mut data: Vec<RT> = self.graph_query(graph, query)?.data;
+        if data.len() > 1 {
+            return Err(GraphError::LengthMismatch.with_detail(format!("Expected at most one row but got {}", data.len())));
+        }
+        Ok(data.pop())
+    }
+
+    /// Send a graph query and ignore the result data.
+    /// The header and statistics are kept but values returned by the query e.g. `CREATE (n) RETURN id(n)`
+    /// are dropped, use `graph_query` to keep them
     fn graph_query_void<Q>(
         &mut self,
         graph: &str,
         query: Q,
     ) -> RedisResult<GraphResponse<()>> where Q: Into<GraphQuery> {
+        query.into().to_cmd(graph)?.query(self)
+    }
+
+    /// Send a graph query and return its parsed statistics e.g. the number of created nodes
+    fn graph_execute<Q>(
+        &mut self,
+        graph: &str,
+        query: Q,
+    ) -> RedisResult<GraphStatistics> where Q: Into<GraphQuery> {
+        Ok(self.graph_query_void(graph, query)?.get_statistics())
+    }
+
+    /// Constructs the execution plan of the query without running it
+    /// Each item is one operation of the plan e.g. "Results" or "    All Node Scan | (n)"
+    fn graph_explain<Q>(
+        &mut self,
+        graph: &str,
+        query: Q,
+    ) -> RedisResult<Vec<String>> where Q: Into<GraphQuery> {
+        cmd("GRAPH.EXPLAIN")
+            .arg(graph)
+            .arg(query.into().checked_query()?)
+            .query(self)
+    }
+
+    /// Runs the query and returns the execution plan with the records produced and the execution time of each operation
+    fn graph_profile<Q>(
+        &mut self,
+        graph: &str,
+        query: Q,
+    ) -> RedisResult<Vec<ProfileStep>> where Q: Into<GraphQuery> {
         let query = query.into();
-        cmd(query.read_type())
+        cmd("GRAPH.PROFILE")
             .arg(graph)
-            .arg(query.construct_query())
-            .arg("--compact")
+            .arg(query.checked_query()?)
             .query(self)
     }
 
+    /// Deletes the graph and all its entities
+    /// Returns the status message of redis graph e.g. "Graph removed, internal execution time: 0.01 milliseconds"
+    fn graph_delete(&mut self, graph: &str) -> RedisResult<String> {
+        cmd("GRAPH.DELETE")
+            .arg(graph)
+            .query(self)
+    }
+
+    /// Creates a node with the label for every row in a single query by passing the rows as one parameter
+    /// Returns the statistics of the query e.g. `nodes_created`
+    fn create_nodes<P: Into<Parameter>>(
+        &mut self,
+        graph: &str,
+        label: &str,
+        rows: Vec<HashMap<String, P>>,
+    ) -> RedisResult<GraphStatistics> {
+        self.graph_execute(graph, create_nodes_query(label, rows))
+    }
+
+    /// Returns true if a key with the name of the graph exists
+    fn graph_exists(&mut self, graph: &str) -> RedisResult<bool> {
+        cmd("EXISTS")
+            .arg(graph)
+            .query(self)
+    }
+
+    /// Copies the graph and all its entities to a new graph
+    /// Fails if the destination graph already exists
+    fn copy_graph(&mut self, src: &str, dest: &str) -> RedisResult<()> {
+        cmd("GRAPH.COPY")
+            .arg(src)
+            .arg(dest)
+            .query(self)
+    }
+
+    /// Returns the slowest queries of the graph
+    fn slowlog(&mut self, graph: &str) -> RedisResult<Vec<SlowlogEntry>> {
+        cmd("GRAPH.SLOWLOG")
+            .arg(graph)
+            .query(self)
+    }
+
+    /// Clears the slowlog of the graph
+    fn slowlog_reset(&mut self, graph: &str) -> RedisResult<()> {
+        cmd("GRAPH.SLOWLOG")
+            .arg(graph)
+            .arg("RESET")
+            .query(self)
+    }
+
+    /// Returns the names of all graphs on the server
+    fn list_graphs(&mut self) -> RedisResult<Vec<String>> {
+        cmd("GRAPH.LIST").query(self)
+    }
+
+    /// Returns the version of the loaded redis graph module from `MODULE LIST` e.g. "2.12.10"
+    fn graph_version(&mut self) -> RedisResult<String> {
+        let modules: Value = cmd("MODULE").arg("LIST").query(self)?;
+        graph_module_version(&modules)
+    }
+
+    /// Creates an index on the property of nodes with the label
+    /// Returns the statistics of the query e.g. `indices_created`
+    fn create_index(&mut self, graph: &str, label: &str, property: &str) -> RedisResult<GraphStatistics> {
+        self.graph_execute(graph, create_index_query(label, property))
+    }
+
+    /// Drops the index on the property of nodes with the label
+    /// Returns the statistics of the query e.g. `indices_deleted`
+    fn drop_index(&mut self, graph: &str, label: &str, property: &str) -> RedisResult<GraphStatistics> {
+        self.graph_execute(graph, drop_index_query(label, property))
+    }
+
+    /// Returns all indexes of the graph
+    fn indexes(&mut self, graph: &str) -> RedisResult<Vec<GraphIndex>> {
+        Ok(self.graph_query(graph, indexes_query())?.data)
+    }
 
     /// Returns a vector where the index is a label id and the value at that index is the corresponding label name
     fn labels(&mut self, graph: &str) -> RedisResult<Vec<String>> {
@@ -51,5 +273,106 @@ pub trait GraphCommands: ConnectionLike + Sized {
     }
 }
 
+/// Builds the batched query of `create_nodes`
+pub(crate) fn create_nodes_query<P: Into<Parameter>>(label: &str, rows: Vec<HashMap<String, P>>) -> GraphQuery {
+    let mut query = GraphQuery::from(format!(
+        "UNWIND $batch AS row CREATE (n:{}) SET n = row",
+        cypher_identifier(label)
+    ));
+    query.add_parameter("batch", rows);
+    query
+}
+
+/// Builds the query of `create_index`
+pub(crate) fn create_index_query(label: &str, property: &str) -> GraphQuery {
+    GraphQuery::from(format!(
+        "CREATE INDEX FOR (n:{}) ON (n.{})",
+        cypher_identifier(label),
+        cypher_identifier(property)
+    ))
+}
+
+/// Builds the query of `drop_index`
+pub(crate) fn drop_index_query(label: &str, property: &str) -> GraphQuery {
+    GraphQuery::from(format!(
+        "DROP INDEX ON :{}({})",
+        cypher_identifier(label),
+        cypher_identifier(property)
+    ))
+}
+
+/// Query of `indexes` which only yields the columns every supported redis graph version has
+pub(crate) fn indexes_query() -> GraphQuery {
+    query!(
+        "CALL db.indexes() YIELD label, properties, types, entitytype, status RETURN label, properties, types, entitytype, status",
+        true
+    )
+}
+
+/// Finds the graph module in the reply of `MODULE LIST` and formats its version like
+/// redis does with `major * 10000 + minor * 100 + patch`
+pub(crate) fn graph_module_version(modules: &Value) -> RedisResult<String> {
+    let modules: Vec<Vec<Value>> = from_redis_value(modules)?;
+    for module in modules {
+        let mut name = None;
+        let mut version = None;
+        for pair in module.chunks(2) {
+            if let [key, value] = pair {
+                match from_redis_value::<String>(key)?.as_str() {
+                    "name" => name = Some(from_redis_value::<String>(value)?),
+                    "ver" => version = Some(from_redis_value::<i64>(value)?),
+                    _ => {}
+                }
+            }
+        }
+        if let (Some(name), Some(version)) = (name, version) {
+            if name.eq_ignore_ascii_case("graph") {
+                return Ok(format!("{}.{}.{}", version / 10000, version / 100 % 100, version % 100));
+            }
+        }
+    }
+    Err(GraphError::InvalidResponse.with_detail("The graph module is not loaded"))
+}
+
+/// Zips every row of `graph_query_maps` with the header names
+pub(crate) fn rows_to_maps(response: GraphResponse<Vec<GraphValue>>) -> Vec<HashMap<String, GraphValue>> {
+    let (header, data, _) = response.into_parts();
+    data.into_iter()
+        .map(|row| header.iter().cloned().zip(row).collect())
+        .collect()
+}
+
 impl<T> GraphCommands for T where T: ConnectionLike {}
 
+/// Object safe version of [`GraphCommands`] for storing a connection as `Box<dyn DynGraphCommands>`.
+/// Generic methods can not be called on trait objects so the rows are always returned as `GraphValue`s
+/// which have to be converted with `from_graph_value` afterwards
+///
+/// ## Example
+/// ```no_run
+/// # use redisgraphio::*;
+/// # fn example(client: redis::Client) -> redis::RedisResult<()> {
+/// let mut con: Box<dyn DynGraphCommands> = Box::new(client.get_connection()?);
+/// let response = con.graph_query_value("graph", query!("RETURN 1"))?;
+/// let data: Vec<(i64,)> = response.data.into_iter().map(from_graph_value).collect::<redis::RedisResult<_>>()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait DynGraphCommands {
+    /// Send a graph query and return every row as a `GraphValue::Array`
+    fn graph_query_value(&mut self, graph: &str, query: GraphQuery) -> RedisResult<GraphResponse<GraphValue>>;
+
+    /// Send a graph query and return its parsed statistics e.g. the number of created nodes
+    fn graph_execute_value(&mut self, graph: &str, query: GraphQuery) -> RedisResult<GraphStatistics>;
+}
+
+impl<T> DynGraphCommands for T where T: GraphCommands {
+    fn graph_query_value(&mut self, graph: &str, query: GraphQuery) -> RedisResult<GraphResponse<GraphValue>> {
+        self.graph_query(graph, query)
+    }
+
+    fn graph_execute_value(&mut self, graph: &str, query: GraphQuery) -> RedisResult<GraphStatistics> {
+        self.graph_execute(graph, query)
+    }
+}
+
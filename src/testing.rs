@@ -0,0 +1,140 @@
+//! Builders for raw replies in the compact wire format of redis graph.
+//! They allow testing [`FromGraphValue`](crate::FromGraphValue) implementations without a server.
+//!
+//! ## Example
+//! ```
+//! use redisgraphio::{testing::*, GraphResponse, Node};
+//!
+//! let reply = mock_response(&["n"], vec![vec![mock_node(1, &[0], vec![(0, mock_string("a"))])]], &[]);
+//! let response: GraphResponse<(Node,)> = redis::from_redis_value(&reply).unwrap();
+//! assert_eq!(response.data[0].0.id, 1);
+//! ```
+use redis::Value;
+
+use crate::parse::types::*;
+
+fn typed(type_: i64, value: Value) -> Value {
+    Value::Bulk(vec![Value::Int(type_), value])
+}
+
+/// Splits a typed value into its type and its raw value
+fn untyped(value: Value) -> (Value, Value) {
+    match value {
+        Value::Bulk(mut items) if items.len() == 2 => {
+            let raw = items.pop().unwrap();
+            (items.pop().unwrap(), raw)
+        }
+        value => panic!("{:?} is not a typed value", value),
+    }
+}
+
+fn properties(properties: Vec<(i64, Value)>) -> Value {
+    Value::Bulk(
+        properties
+            .into_iter()
+            .map(|(id, value)| {
+                let (type_, raw) = untyped(value);
+                Value::Bulk(vec![Value::Int(id), type_, raw])
+            })
+            .collect(),
+    )
+}
+
+/// Reply for `null`
+pub fn mock_null() -> Value {
+    typed(VALUE_NULL, Value::Nil)
+}
+
+/// Reply for a string
+pub fn mock_string(value: &str) -> Value {
+    typed(VALUE_STRING, Value::Data(value.as_bytes().to_vec()))
+}
+
+/// Reply for an integer
+pub fn mock_integer(value: i64) -> Value {
+    typed(VALUE_INTEGER, Value::Int(value))
+}
+
+/// Reply for a boolean
+pub fn mock_bool(value: bool) -> Value {
+    typed(VALUE_BOOLEAN, Value::Data(value.to_string().into_bytes()))
+}
+
+/// Reply for a double
+pub fn mock_double(value: f64) -> Value {
+    typed(VALUE_DOUBLE, Value::Data(value.to_string().into_bytes()))
+}
+
+/// Reply for an array of values built with the other functions of this module
+pub fn mock_array(values: Vec<Value>) -> Value {
+    typed(VALUE_ARRAY, Value::Bulk(values))
+}
+
+/// Reply for a map of values built with the other functions of this module
+pub fn mock_map(entries: Vec<(&str, Value)>) -> Value {
+    typed(
+        VALUE_MAP,
+        Value::Bulk(
+            entries
+                .into_iter()
+                .flat_map(|(key, value)| [Value::Data(key.as_bytes().to_vec()), value])
+                .collect(),
+        ),
+    )
+}
+
+/// Reply for a point
+pub fn mock_point(latitude: f64, longitude: f64) -> Value {
+    typed(
+        VALUE_POINT,
+        Value::Bulk(vec![
+            Value::Data(latitude.to_string().into_bytes()),
+            Value::Data(longitude.to_string().into_bytes()),
+        ]),
+    )
+}
+
+/// Reply for a node with label ids and properties as pairs of property key id and value
+pub fn mock_node(id: i64, label_ids: &[i64], props: Vec<(i64, Value)>) -> Value {
+    typed(
+        VALUE_NODE,
+        Value::Bulk(vec![
+            Value::Int(id),
+            Value::Bulk(label_ids.iter().map(|&id| Value::Int(id)).collect()),
+            properties(props),
+        ]),
+    )
+}
+
+/// Reply for a relationship with properties as pairs of property key id and value
+pub fn mock_relationship(id: i64, type_id: i64, src: i64, dest: i64, props: Vec<(i64, Value)>) -> Value {
+    typed(
+        VALUE_EDGE,
+        Value::Bulk(vec![
+            Value::Int(id),
+            Value::Int(type_id),
+            Value::Int(src),
+            Value::Int(dest),
+            properties(props),
+        ]),
+    )
+}
+
+/// Reply for a path built from `mock_node` and `mock_relationship` values
+pub fn mock_path(nodes: Vec<Value>, relationships: Vec<Value>) -> Value {
+    typed(VALUE_PATH, Value::Bulk(vec![mock_array(nodes), mock_array(relationships)]))
+}
+
+/// Reply of a whole query where every row contains one value per header column
+pub fn mock_response(header: &[&str], rows: Vec<Vec<Value>>, statistics: &[&str]) -> Value {
+    Value::Bulk(vec![
+        Value::Bulk(
+            header
+                .iter()
+                .map(|name| Value::Bulk(vec![Value::Int(1), Value::Data(name.as_bytes().to_vec())]))
+                .collect(),
+        ),
+        Value::Bulk(rows.into_iter().map(Value::Bulk).collect()),
+        Value::Bulk(statistics.iter().map(|stat| Value::Data(stat.as_bytes().to_vec())).collect()),
+    ])
+}
@@ -1,8 +1,12 @@
 use crate::{
-    query, GeoPoint, GraphCommands, GraphMap, GraphPath, GraphQuery, GraphValue,
-    PropertyAccess, GraphStatistic
+    from_graph_value, from_graph_value_ref, query, GeoPoint, GraphCommands, GraphMap, GraphPath, GraphQuery, GraphValue, Node, Relationship,
+    PropertyAccess, GraphPipeline, GraphResponse, GraphSchema, GraphStatistic, GraphStatistics, Parameter, ProfileStep, SlowlogEntry, GraphError, ColumnType, RetryPolicy, IntBool, DynGraphCommands
 };
 
+use crate::testing::*;
+use redis::{ConnectionLike, RedisResult, Value};
+use std::{borrow::Cow, collections::{BTreeMap, HashMap, HashSet}, time::Duration};
+
 use paste::paste;
 
 #[test]
@@ -10,16 +14,18 @@ fn test_query_macro() {
     assert_eq!(
         query!("Return 1"),
         GraphQuery {
-            query: "Return 1",
+            query: "Return 1".into(),
             read_only: false,
+            timeout: None,
             params: vec![]
         }
     );
     assert_eq!(
         query!("Return 1", true),
         GraphQuery {
-            query: "Return 1",
+            query: "Return 1".into(),
             read_only: true,
+            timeout: None,
             params: vec![]
         }
     );
@@ -29,8 +35,9 @@ fn test_query_macro() {
             "b" => "test"
         }),
         GraphQuery {
-            query: "Return 1",
+            query: "Return 1".into(),
             read_only: false,
+            timeout: None,
             params: vec![("a", 4.into()), ("b", "test".into())]
         }
     );
@@ -40,11 +47,373 @@ fn test_query_macro() {
             "b" => "test"
         }, true),
         GraphQuery {
-            query: "Return 1",
+            query: "Return 1".into(),
             read_only: true,
+            timeout: None,
             params: vec![("a", 4.5.into()), ("b", "test".into())]
         }
     );
+    let timeout = 100;
+    assert_eq!(
+        query!("Return 1", timeout = timeout),
+        GraphQuery {
+            query: "Return 1".into(),
+            read_only: false,
+            timeout: Some(100),
+            params: vec![]
+        }
+    );
+    assert_eq!(
+        query!("Return 1", {"a" => 1}, true, timeout = 5),
+        GraphQuery {
+            query: "Return 1".into(),
+            read_only: true,
+            timeout: Some(5),
+            params: vec![("a", 1.into())]
+        }
+    );
+    let label = "User";
+    assert_eq!(
+        query!(format!("Match (n:{}) Return n", label)),
+        GraphQuery::from(String::from("Match (n:User) Return n"))
+    );
+    assert_eq!(
+        query!("Return $a, $b", {
+            "a" => true,
+            "b" => false
+        }).construct_query(),
+        "CYPHER a=true b=false Return $a, $b"
+    );
+    assert_eq!(
+        query!("Return $a, $b", {
+            "a" => None::<i32>,
+            "b" => Some("test")
+        }).construct_query(),
+        r#"CYPHER a=null b="test" Return $a, $b"#
+    );
+    assert_eq!(
+        query!("Match (u:User) Where id(u) In $ids Return u", {
+            "ids" => vec![1, 2, 3]
+        }).construct_query(),
+        "CYPHER ids=[1,2,3] Match (u:User) Where id(u) In $ids Return u"
+    );
+    assert_eq!(
+        query!("Return $a", {
+            "a" => vec![vec!["a", "b"], vec![]]
+        }).construct_query(),
+        r#"CYPHER a=[["a","b"],[]] Return $a"#
+    );
+}
+
+#[test]
+fn test_query_default() {
+    let query = GraphQuery::default();
+    assert_eq!(query.query, "");
+    assert!(query.params.is_empty());
+    assert!(!query.read_only);
+    assert_eq!(query.timeout, None);
+    assert_eq!(GraphQuery { query: "Return 1".into(), ..Default::default() }, query!("Return 1"));
+}
+
+#[test]
+fn test_query_builder() {
+    assert_eq!(GraphQuery::builder("Return 1").build(), query!("Return 1"));
+    assert_eq!(
+        GraphQuery::builder(format!("Return ${}", "a")).param("a", 1).param("b", "x").read_only(true).timeout(10).build(),
+        query!(format!("Return ${}", "a"), {"a" => 1, "b" => "x"}, true, timeout = 10)
+    );
+    let mut con = MockConnection::new(|_| Ok(int_rows(&[1])));
+    con.graph_query_void("test", GraphQuery::builder("Return $a").param("a", 1)).unwrap();
+    con.graph_query_void("test", query!("Return $a", {"a" => 1})).unwrap();
+    assert_eq!(con.commands[0], con.commands[1]);
+}
+
+#[test]
+fn test_query_with_retry() {
+    let mut failures = 1;
+    let mut con = MockConnection::new(move |_| {
+        if failures > 0 {
+            failures -= 1;
+            return Err(redis::parse_redis_value(b"-BUSY Redis is busy running a script\r\n").unwrap_err());
+        }
+        Ok(int_rows(&[1]))
+    });
+    let policy = RetryPolicy::new(2).backoff(Duration::from_millis(1));
+    let response: GraphResponse<(i64,)> = con.graph_query_with_retry("test", query!("Return 1"), &policy).unwrap();
+    assert_eq!(response.data, vec![(1,)]);
+    assert_eq!(con.commands.len(), 2);
+}
+
+#[test]
+fn test_retry_only_transient_errors() {
+    let mut con = MockConnection::new(|_| Err((redis::ErrorKind::TryAgain, "Try again").into()));
+    let result = con.graph_query_with_retry::<_, (i64,)>("test", query!("Return 1"), &RetryPolicy::new(3));
+    assert_eq!(result.unwrap_err().kind(), redis::ErrorKind::TryAgain);
+    assert_eq!(con.commands.len(), 3);
+    let mut con = MockConnection::new(|_| Ok(string_rows(&["a"])));
+    assert!(con.graph_query_with_retry::<_, (i64,)>("test", query!("Return 'a'"), &RetryPolicy::new(3)).is_err());
+    assert_eq!(con.commands.len(), 1);
+    assert!(!RetryPolicy::is_retryable(&GraphError::TypeMismatch.into(), true));
+    assert_eq!(RetryPolicy::default().max_attempts(), 1);
+    assert_eq!(RetryPolicy::new(0).max_attempts(), 1);
+}
+
+#[test]
+fn test_retry_timeouts_only_read_only() {
+    // The reply of a client side timeout is still pending on the connection so it is never retried
+    let mut con = MockConnection::new(|_| Err(std::io::Error::new(std::io::ErrorKind::TimedOut, "timed out").into()));
+    let result = con.graph_query_with_retry::<_, (i64,)>("test", query!("Match (a:A) Return a", true), &RetryPolicy::new(3));
+    assert!(result.unwrap_err().is_timeout());
+    assert_eq!(con.commands.len(), 1);
+
+    let server_timeout = || redis::parse_redis_value(b"-Query timed out\r\n").unwrap_err();
+    let mut con = MockConnection::new(move |_| Err(server_timeout()));
+    let result = con.graph_query_with_retry::<_, (i64,)>("test", query!("Create (:A)"), &RetryPolicy::new(3));
+    assert_eq!(result.unwrap_err().code(), Some("Query"));
+    assert_eq!(con.commands.len(), 1);
+    let result = con.graph_query_with_retry::<_, (i64,)>("test", query!("Match (a:A) Return a", true), &RetryPolicy::new(3));
+    assert_eq!(result.unwrap_err().code(), Some("Query"));
+    assert_eq!(con.commands.len(), 4);
+}
+
+#[test]
+fn test_dyn_graph_commands() {
+    let mut con: Box<dyn DynGraphCommands> = Box::new(MockConnection::new(|_| Ok(int_rows(&[1, 2]))));
+    let response = con.graph_query_value("test", query!("Unwind [1, 2] As x Return x")).unwrap();
+    let data: Vec<(i64,)> = response.data.into_iter().map(from_graph_value).collect::<RedisResult<_>>().unwrap();
+    assert_eq!(data, vec![(1,), (2,)]);
+    assert_eq!(con.graph_execute_value("test", query!("Return 1")).unwrap(), GraphStatistics::default());
+}
+
+#[test]
+fn test_query_node_ids() {
+    let mut con = MockConnection::new(|_| Ok(mock_response(
+        &["n"],
+        vec![
+            vec![mock_node(4, &[0, 2], vec![(0, mock_string("a"))])],
+            vec![mock_node(7, &[], vec![])],
+        ],
+        &[],
+    )));
+    let ids = con.graph_query_node_ids("test", query!("Match (n) Return n")).unwrap();
+    assert_eq!(ids, vec![(4, vec![0, 2]), (7, vec![])]);
+    let mut con = MockConnection::new(|_| Ok(int_rows(&[1])));
+    let err = con.graph_query_node_ids("test", query!("Return 1")).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::TypeMismatch));
+}
+
+#[test]
+fn test_query_node_ids_live() {
+    let con = &mut sync_con();
+    let created: Vec<(i64,)> = con.graph_query("test", query!("Create (n:NodeIdTest) Return id(n)")).unwrap().data;
+    let schema = GraphSchema::load(con, "test").unwrap();
+    let ids = con
+        .graph_query_node_ids("test", query!("Match (n:NodeIdTest) Where id(n) = $id Return n", {"id" => created[0].0}))
+        .unwrap();
+    assert_eq!(ids, vec![(created[0].0, vec![schema.label_id("NodeIdTest").unwrap()])]);
+    con.graph_query_void("test", query!("Match (n:NodeIdTest) Delete n")).unwrap();
+}
+
+#[test]
+fn test_query_collect() {
+    let mut con = MockConnection::new(|_| Ok(int_rows(&[3, 1, 3, 2, 1])));
+    let distinct: HashSet<(i64,)> = con.graph_query_collect("test", query!("Unwind [3, 1, 3, 2, 1] As x Return x")).unwrap();
+    assert_eq!(distinct, HashSet::from([(1,), (2,), (3,)]));
+    let mut con = MockConnection::new(|_| Ok(string_rows(&["a"])));
+    assert!(con.graph_query_collect::<_, (i64,), HashSet<_>>("test", query!("Return 'a'")).is_err());
+}
+
+#[test]
+fn test_query_maps() {
+    let mut con = MockConnection::new(|_| Ok(mock_response(&["a", "b"], vec![vec![mock_integer(1), mock_integer(2)]], &[])));
+    let rows = con.graph_query_maps("test", query!("RETURN 1 AS a, 2 AS b")).unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["a"], GraphValue::Integer(1));
+    assert_eq!(rows[0]["b"], GraphValue::Integer(2));
+    assert_eq!(rows[0].len(), 2);
+}
+
+#[test]
+fn test_query_maps_live() {
+    let rows = sync_con().graph_query_maps("test", query!("RETURN 1 AS a, 2 AS b")).unwrap();
+    assert_eq!(rows, vec![HashMap::from([
+        ("a".to_string(), GraphValue::Integer(1)),
+        ("b".to_string(), GraphValue::Integer(2)),
+    ])]);
+}
+
+#[test]
+fn test_void_keeps_header() {
+    let mut con = MockConnection::new(|_| Ok(mock_response(&["id(n)"], vec![vec![mock_integer(7)]], &["Nodes created: 1"])));
+    let response = con.graph_query_void("test", query!("CREATE (n) RETURN id(n)")).unwrap();
+    assert_eq!(response.header_names(), vec!["id(n)"]);
+    assert_eq!(response.len(), 1);
+    assert_eq!(response.get_statistics().nodes_created, 1);
+}
+
+#[test]
+fn test_parameter_names() {
+    let mut con = MockConnection::new(|_| Ok(int_rows(&[1])));
+    for name in ["a", "_a", "camelCase", "snake_case_2"] {
+        let query = GraphQuery::builder("Return 1").param(name, 1).build();
+        assert!(con.graph_query_void("test", query).is_ok(), "{name} should be valid");
+    }
+    for name in ["", "1a", "a b", "a=1", "a-b", "ü"] {
+        let query = GraphQuery::builder("Return 1").param(name, 1).build();
+        let err = con.graph_query_void("test", query).unwrap_err();
+        assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::InvalidParameter), "{name:?} should be invalid");
+    }
+    assert_eq!(con.commands.len(), 4);
+    let mut pipeline = GraphPipeline::new();
+    pipeline.query("test", GraphQuery::builder("Return 1").param("1a", 1));
+    assert!(pipeline.execute::<_, (i64,)>(&mut con).is_err());
+    assert_eq!(con.commands.len(), 4);
+}
+
+#[test]
+fn test_raw_parameter() {
+    let literal = "localdatetime({year: 2020, month: \"1\"})";
+    let query = query!("Return $a, $b", {"a" => Parameter::Raw(literal.to_string()), "b" => literal});
+    assert_eq!(
+        query.params_prefix(),
+        "CYPHER a=localdatetime({year: 2020, month: \"1\"}) b=\"localdatetime({year: 2020, month: \\\"1\\\"})\" "
+    );
+}
+
+#[test]
+fn test_runtime_params() {
+    let params: Vec<(&'static str, Parameter)> = ["a", "b"]
+        .into_iter()
+        .zip(1..)
+        .map(|(key, value)| (key, Parameter::from(value)))
+        .collect();
+    assert_eq!(
+        query!("Return $a, $b, $c", {"c" => true}).with_params(params).construct_query(),
+        "CYPHER c=true a=1 b=2 Return $a, $b, $c"
+    );
+    assert_eq!(
+        query!("Return $a").with_params([("a", "x")]).construct_query(),
+        r#"CYPHER a="x" Return $a"#
+    );
+}
+
+#[test]
+fn test_params_prefix() {
+    assert_eq!(query!("Return 1").params_prefix(), "");
+    assert_eq!(query!("Return $a", {"a" => 5}).params_prefix(), "CYPHER a=5 ");
+    assert_eq!(query!("Return $a", {"a" => -2.5}).params_prefix(), "CYPHER a=-2.5 ");
+    assert_eq!(query!("Return $a", {"a" => "b\"c"}).params_prefix(), r#"CYPHER a="b\"c" "#);
+    assert_eq!(
+        query!("Return $a, $b", {"a" => 1, "b" => vec![Some(true), None]}).params_prefix(),
+        "CYPHER a=1 b=[true,null] "
+    );
+}
+
+#[test]
+fn test_slice_and_array_params() {
+    let ids: Vec<i64> = vec![1, 2, 3];
+    let slice: &[i64] = &ids;
+    assert_eq!(query!("Return $a", {"a" => slice}).params_prefix(), "CYPHER a=[1,2,3] ");
+    assert_eq!(query!("Return $a", {"a" => ["x", "y"]}).params_prefix(), r#"CYPHER a=["x","y"] "#);
+    assert_eq!(Parameter::from(&ids[..0]), Parameter::Array(vec![]));
+    assert_eq!(Parameter::from(slice), Parameter::from(ids));
+}
+
+#[test]
+fn test_slice_param_query() {
+    let ids = [3_i64, 1, 2];
+    let data: Vec<(i64,)> = sync_con()
+        .graph_query("test", query!("Unwind $ids As x Return x", {"ids" => &ids[..]}))
+        .unwrap()
+        .data;
+    assert_eq!(data, vec![(3,), (1,), (2,)]);
+}
+
+#[test]
+fn test_single_graph_query_type() {
+    // query! has to build the GraphQuery that is exported at the crate root
+    let _: crate::GraphQuery = query!("Return 1");
+}
+
+#[test]
+fn test_query_macro_read_only_expr() {
+    let read_only = true;
+    assert!(query!("Match (n) Return n", read_only).read_only);
+    assert!(query!("Return $a", {"a" => 1}, read_only).read_only);
+    assert!(!query!("Return $a", {"a" => 1}, !read_only, timeout = 5).read_only);
+    assert_eq!(query!("Return 1", !read_only, timeout = 5).timeout, Some(5));
+    assert_eq!(query!("Return 1", timeout = 5).timeout, Some(5));
+    assert!(!query!("Return 1", timeout = 5).read_only);
+}
+
+#[test]
+fn test_string_parameter_escaping() {
+    assert_eq!(
+        query!("Return $a", {"a" => "quote\" single' backslash\\ newline\n tab\t bell\u{7} ü"}).construct_query(),
+        r#"CYPHER a="quote\" single\' backslash\\ newline\n tab\t bell\u0007 ü" Return $a"#
+    );
+}
+
+#[test]
+fn test_point_parameter() {
+    let point = GeoPoint { latitude: 32.5, longitude: -4.25 };
+    assert_eq!(
+        query!("Create (:Place {loc: $p})", {"p" => point}).construct_query(),
+        "CYPHER p=point({latitude: 32.5, longitude: -4.25}) Create (:Place {loc: $p})"
+    );
+}
+
+#[test]
+fn test_point_to_array() {
+    let point = GraphValue::Point(GeoPoint { latitude: 32.5, longitude: -4.25 });
+    assert_eq!(from_graph_value::<[f64; 2]>(point).unwrap(), [32.5, -4.25]);
+    assert!(from_graph_value::<[f64; 2]>(GraphValue::Array(vec![GraphValue::Double(1.0), GraphValue::Double(2.0)])).is_err());
+}
+
+#[test]
+fn test_point_parameter_round_trip() {
+    let con = &mut sync_con();
+    let point = GeoPoint { latitude: 32.5, longitude: 34.75 };
+    con.graph_query_void("test_point", query!("Create (:Place {loc: $p})", {"p" => point.clone()})).unwrap();
+    let data: Vec<(GeoPoint,)> = con
+        .graph_query("test_point", query!("Match (p:Place) Where p.loc = $p Return p.loc", {"p" => point.clone()}))
+        .unwrap()
+        .data;
+    con.graph_delete("test_point").unwrap();
+    assert_eq!(data, vec![(point,)]);
+}
+
+#[test]
+fn test_u64_parameter() {
+    assert_eq!(Parameter::from(i64::MAX as u64 - 1), Parameter::Int(i64::MAX - 1));
+    assert_eq!(Parameter::from(i64::MAX as u64), Parameter::Int(i64::MAX));
+    assert_eq!(Parameter::from(u64::MAX), Parameter::Int(i64::MAX));
+}
+
+#[test]
+fn test_map_parameter() {
+    let inner = Parameter::from(vec![
+        ("tags".to_string(), Parameter::from(vec!["a", "b"])),
+        ("score".to_string(), Parameter::from(1.5)),
+    ]);
+    assert_eq!(
+        query!("Create (n:User $props)", {
+            "props" => vec![
+                ("name".to_string(), Parameter::from("test")),
+                ("inner".to_string(), inner)
+            ]
+        }).construct_query(),
+        r#"CYPHER props={name: "test", inner: {tags: ["a","b"], score: 1.5}} Create (n:User $props)"#
+    );
+    let map: HashMap<String, i32> = [
+        ("b".to_string(), 2),
+        ("a".to_string(), 1),
+        ("needs quoting`".to_string(), 3),
+    ].into_iter().collect();
+    assert_eq!(
+        query!("Return $a", {"a" => map}).construct_query(),
+        "CYPHER a={a: 1, b: 2, `needs quoting```: 3} Return $a"
+    );
 }
 
 fn get_client() -> redis::Client {
@@ -60,6 +429,77 @@ fn sync_con() -> redis::Connection {
     get_client().get_connection().unwrap()
 }
 
+type MockHandler = Box<dyn FnMut(&str) -> RedisResult<Value>>;
+
+/// Connection which records every command and answers it with the reply of the handler
+struct MockConnection {
+    commands: Vec<String>,
+    handler: MockHandler,
+}
+
+impl MockConnection {
+    fn new(handler: impl FnMut(&str) -> RedisResult<Value> + 'static) -> Self {
+        MockConnection { commands: vec![], handler: Box::new(handler) }
+    }
+}
+
+impl ConnectionLike for MockConnection {
+    fn req_packed_command(&mut self, cmd: &[u8]) -> RedisResult<Value> {
+        let cmd = String::from_utf8_lossy(cmd).into_owned();
+        let reply = (self.handler)(&cmd);
+        self.commands.push(cmd);
+        reply
+    }
+
+    fn req_packed_commands(&mut self, cmd: &[u8], offset: usize, count: usize) -> RedisResult<Vec<Value>> {
+        let reply = self.req_packed_command(cmd)?;
+        match reply {
+            Value::Bulk(values) => Ok(values.into_iter().skip(offset).take(count).collect()),
+            value => Ok(vec![value]),
+        }
+    }
+
+    fn get_db(&self) -> i64 {
+        0
+    }
+
+    fn check_connection(&mut self) -> bool {
+        true
+    }
+
+    fn is_open(&self) -> bool {
+        true
+    }
+}
+
+// Answers synchronously so the returned futures do not hold on to the handler
+#[cfg(any(feature = "tokio-comp", feature = "async-std-comp"))]
+impl redis::aio::ConnectionLike for MockConnection {
+    fn req_packed_command<'a>(&'a mut self, cmd: &'a redis::Cmd) -> redis::RedisFuture<'a, Value> {
+        let reply = ConnectionLike::req_packed_command(self, &cmd.get_packed_command());
+        Box::pin(async move { reply })
+    }
+
+    fn req_packed_commands<'a>(
+        &'a mut self,
+        cmd: &'a redis::Pipeline,
+        offset: usize,
+        count: usize,
+    ) -> redis::RedisFuture<'a, Vec<Value>> {
+        let reply = ConnectionLike::req_packed_commands(self, &cmd.get_packed_pipeline(), offset, count);
+        Box::pin(async move { reply })
+    }
+
+    fn get_db(&self) -> i64 {
+        0
+    }
+}
+
+/// Compact graph response with a single string column
+fn string_rows(rows: &[&str]) -> Value {
+    mock_response(&["name"], rows.iter().map(|row| vec![mock_string(row)]).collect(), &["Cached execution: 0"])
+}
+
 #[cfg(feature = "tokio-comp")]
 fn tokio_runtime() -> tokio::runtime::Runtime {
     tokio::runtime::Builder::new_current_thread()
@@ -110,10 +550,22 @@ test_parse! {ints,
         i8 => 5,
         i16 => 6,
         i32 => 7,
-        i64 => 0x7fffffffffffffff // Redis only allows 64bit ints so this is the expected interger overflow value
+        i64 => 0x7fffffffffffffff // Redis graph clamps integer literals to 64bit so this is the expected value
     }
 }
 
+// The standard library only implements PartialEq and Debug for tuples of up to 12 elements
+#[test]
+fn parse_wide_return() {
+    type Wide = (i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, String);
+    let data: Vec<Wide> = sync_con()
+        .graph_query("test", query!("Return 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 'thirteen'"))
+        .unwrap()
+        .data;
+    let (first, .., twelfth, thirteenth) = data.into_iter().next().unwrap();
+    assert_eq!((first, twelfth, thirteenth.as_str()), (1, 12, "thirteen"));
+}
+
 test_parse! {double,
     query!("Return 1.0, 3.3, 4.67"),
     {
@@ -123,6 +575,15 @@ test_parse! {double,
     }
 }
 
+test_parse! {int_to_double,
+    query!("Unwind [1, 2, 3] As x Return count(x), sum(x), 2.5"),
+    {
+        f64 => 3.0,
+        f32 => 6.0,
+        f32 => 2.5
+    }
+}
+
 test_parse! {boolean,
     query!("Return 1.0 = 1.0, 0=1, true"),
     {
@@ -132,6 +593,28 @@ test_parse! {boolean,
     }
 }
 
+test_parse! {boolean_param,
+    query!("Return $a, $b", {
+        "a" => true,
+        "b" => false
+    }),
+    {
+        bool => true,
+        bool => false
+    }
+}
+
+test_parse! {option_param,
+    query!("Return $a, $b", {
+        "a" => None::<String>,
+        "b" => Some(5)
+    }),
+    {
+        Option<String> => None,
+        Option<i32> => Some(5)
+    }
+}
+
 test_parse! {vec,
     query!("Return [1, 2, 3, 4], [5, 6]"),
     {
@@ -161,6 +644,28 @@ test_parse! {string,
     }
 }
 
+test_parse! {vec_param,
+    query!("Return $a, $b", {
+        "a" => vec![1, 2, 3],
+        "b" => vec![vec![1.5], vec![]]
+    }),
+    {
+        Vec<i32> => vec![1, 2, 3],
+        Vec<Vec<f64>> => vec![vec![1.5], vec![]]
+    }
+}
+
+test_parse! {string_escaping,
+    query!("Return $a, $b", {
+        "a" => "\"quoted\" and 'single' \\ backslash",
+        "b" => "line\nbreak\r\n\ttab"
+    }),
+    {
+        String => "\"quoted\" and 'single' \\ backslash".to_string(),
+        String => "line\nbreak\r\n\ttab".to_string()
+    }
+}
+
 test_parse! {map,
     query!("Return {a: 5, b: 4.5, c: [1,2]}"),
     {
@@ -175,6 +680,28 @@ test_parse! {map,
     }
 }
 
+test_parse! {map_param,
+    query!("Return $a", {
+        "a" => vec![
+            ("a".to_string(), Parameter::from(5)),
+            ("b".to_string(), Parameter::from(vec![
+                ("c".to_string(), vec![1, 2])
+            ]))
+        ]
+    }),
+    {
+        GraphMap => GraphMap([
+            ("a".to_string(), GraphValue::Integer(5)),
+            ("b".to_string(), GraphValue::Map(GraphMap([
+                ("c".to_string(), GraphValue::Array(vec![
+                    GraphValue::Integer(1),
+                    GraphValue::Integer(2)
+                ]))
+            ].into_iter().collect())))
+        ].into_iter().collect())
+    }
+}
+
 test_parse! {point,
     query!("Return point({latitude: 32.070794860, longitude: 34.820751118})"),
     {
@@ -217,12 +744,1384 @@ fn test_parse_graphtypes() {
     assert!(res.get_statistic(GraphStatistic::CachedExecution).is_some());
     assert_eq!(res.get_statistic(GraphStatistic::NodesDeleted), Some(2.0));
     assert_eq!(res.get_statistic(GraphStatistic::RelationshipsDeleted), Some(1.0));
+    let stats = res.get_statistics();
+    assert_eq!(stats.nodes_deleted, 2);
+    assert_eq!(stats.relationships_deleted, 1);
+    assert!(stats.execution_time_ms.is_some());
 }
 
 #[test]
-fn test_mappings() {
+fn test_graph_delete() {
     let con = &mut sync_con();
-    con.labels("test").unwrap();
-    con.relationship_types("test").unwrap();
-    con.property_keys("test").unwrap();
+    con.graph_query_void("test_delete", query!("Create (:User {a: 1})"))
+        .unwrap();
+    let status = con.graph_delete("test_delete").unwrap();
+    assert!(status.starts_with("Graph removed"));
+    assert!(con
+        .graph_query_void("test_delete", query!("Match (n) Return n", true))
+        .is_err());
+}
+
+#[test]
+fn test_graph_explain() {
+    let plan = sync_con().graph_explain("test", query!("Match (n) Return n")).unwrap();
+    assert!(!plan.is_empty());
+    assert!(plan.iter().any(|op| op.contains("Results") || op.contains("Scan")));
+}
+
+#[test]
+fn test_profile_step_parse() {
+    assert_eq!(
+        ProfileStep::parse("    Node By Label Scan | (n:User) | Records produced: 3, Execution time: 0.004200 ms"),
+        ProfileStep {
+            operation: "Node By Label Scan".to_string(),
+            records_produced: Some(3),
+            execution_time: Some(0.0042),
+            raw: "    Node By Label Scan | (n:User) | Records produced: 3, Execution time: 0.004200 ms".to_string()
+        }
+    );
+    assert_eq!(
+        ProfileStep::parse("Results | Records produced: 1, Execution time: 0.001 ms").records_produced,
+        Some(1)
+    );
+    let unknown = ProfileStep::parse("Some future format");
+    assert_eq!(unknown.operation, "Some future format");
+    assert_eq!(unknown.records_produced, None);
+    assert_eq!(unknown.execution_time, None);
+}
+
+#[test]
+fn test_graph_profile() {
+    let con = &mut sync_con();
+    con.graph_query_void("test_profile", query!("Create Index On :User(name)")).unwrap();
+    con.graph_query_void("test_profile", query!("Create (:User {name: 'a'}), (:User {name: 'b'})")).unwrap();
+    let steps = con
+        .graph_profile("test_profile", query!("Match (u:User) Where u.name = $name Return u", {"name" => "a"}))
+        .unwrap();
+    con.graph_delete("test_profile").unwrap();
+    assert!(steps.iter().any(|step| step.operation.contains("Index Scan")));
+    for step in steps {
+        assert!(step.records_produced.is_some());
+        assert!(step.execution_time.is_some());
+    }
+}
+
+#[test]
+fn test_statistics_parse() {
+    let stats = GraphStatistics::parse(&[
+        "Labels added: 1",
+        "Nodes created: 2",
+        "Properties set: 3",
+        "Relationships created: 4",
+        "Nodes deleted: 5",
+        "Relationships deleted: 6",
+        "Indices created: 7",
+        "Indices deleted: 8",
+        "Cached execution: 1",
+        "Query internal execution time: 0.1337 milliseconds",
+    ]);
+    assert_eq!(
+        stats,
+        GraphStatistics {
+            labels_added: 1,
+            nodes_created: 2,
+            properties_set: 3,
+            relationships_created: 4,
+            nodes_deleted: 5,
+            relationships_deleted: 6,
+            indices_created: 7,
+            indices_deleted: 8,
+            cached_execution: true,
+            execution_time_ms: Some(0.1337),
+        }
+    );
+    assert_eq!(
+        GraphStatistics::parse(&["Cached execution: 0", "Nodes created: garbage"]),
+        GraphStatistics::default()
+    );
+}
+
+#[test]
+fn test_statistics_execution_time() {
+    let stats = GraphStatistics::parse(&["Query internal execution time: 1.5 milliseconds"]);
+    assert_eq!(stats.execution_time(), Some(std::time::Duration::from_micros(1500)));
+    assert_eq!(GraphStatistics::parse(&["Query internal execution time: slow"]).execution_time(), None);
+    assert_eq!(GraphStatistics { execution_time_ms: Some(-1.0), ..Default::default() }.execution_time(), None);
+}
+
+#[test]
+fn test_response_into_iter() {
+    let response: GraphResponse<(i64,)> = GraphResponse {
+        header: vec![(ColumnType::Scalar, "a".to_string())],
+        data: vec![(1,), (2,), (3,)],
+        statistics: vec![],
+    };
+    let mut sum = 0;
+    for (value,) in &response {
+        sum += value;
+    }
+    for (value,) in response {
+        sum += value;
+    }
+    assert_eq!(sum, 12);
+}
+
+#[test]
+fn test_response_into_parts() {
+    let response = GraphResponse::<(i64,)>::parse_response(&int_rows(&[1, 2])).unwrap();
+    let (header, data, statistics) = response.into_parts();
+    assert_eq!(header, vec!["value".to_string()]);
+    assert_eq!(data, vec![(1,), (2,)]);
+    assert_eq!(statistics, GraphStatistics::default());
+    let response = GraphResponse::<(i64,)>::parse_response(&int_rows(&[3])).unwrap();
+    assert_eq!(response.into_data(), vec![(3,)]);
+}
+
+#[test]
+fn test_response_single() {
+    let parse = |rows: &[i64]| GraphResponse::<(i64,)>::parse_response(&int_rows(rows)).unwrap();
+    assert_eq!(parse(&[]).first(), None);
+    assert_eq!(parse(&[1, 2]).first(), Some(&(1,)));
+    let err = parse(&[]).single().unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::LengthMismatch));
+    assert_eq!(parse(&[1]).single().unwrap(), (1,));
+    let err = parse(&[1, 2]).single().unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::LengthMismatch));
+}
+
+#[test]
+fn test_response_len() {
+    let response = GraphResponse::<(i64,)>::parse_response(&int_rows(&[1, 2])).unwrap();
+    assert_eq!(response.len(), 2);
+    assert!(!response.is_empty());
+    let response = GraphResponse::<(i64,)>::parse_response(&int_rows(&[])).unwrap();
+    assert_eq!(response.len(), 0);
+    assert!(response.is_empty());
+}
+
+#[test]
+fn test_display() {
+    let map = GraphValue::Map(GraphMap([
+        ("b".to_string(), GraphValue::Array(vec![
+            GraphValue::Integer(1),
+            GraphValue::Double(2.0),
+            GraphValue::String("a\"b".to_string()),
+            GraphValue::Array(vec![GraphValue::Null, GraphValue::Boolean(true)]),
+        ])),
+        ("a".to_string(), GraphValue::Map(GraphMap(HashMap::new()))),
+    ].into_iter().collect()));
+    assert_eq!(map.to_string(), r#"{a: {}, b: [1, 2.0, "a\"b", [null, true]]}"#);
+
+    let a = Node::new(0, vec![0, 2], [(1, GraphValue::Integer(3))].into_iter().collect());
+    let b = Node::new(1, vec![1], Default::default());
+    let rel = Relationship::new(0, 0, 0, 1, Default::default());
+    assert_eq!(GraphValue::Node(a.clone()).to_string(), "(:0:2 {1: 3})");
+    assert_eq!(GraphValue::Relation(rel.clone()).to_string(), "[:0]");
+    let path = GraphPath { nodes: vec![a, b], relationships: vec![rel] };
+    assert_eq!(GraphValue::Path(path).to_string(), "(:0:2 {1: 3})-[:0]->(:1)");
+}
+
+#[test]
+fn test_float_conversion() {
+    assert_eq!(from_graph_value::<f64>(GraphValue::Integer(3)).unwrap(), 3.0);
+    assert_eq!(from_graph_value::<f64>(GraphValue::Double(2.5)).unwrap(), 2.5);
+    assert_eq!(from_graph_value::<f32>(GraphValue::Integer(-7)).unwrap(), -7.0);
+    assert!(from_graph_value::<f64>(GraphValue::String("1".to_string())).is_err());
+}
+
+#[test]
+fn test_wide_tuple() {
+    let row = GraphValue::Array((1..=16).map(GraphValue::Integer).collect());
+    type Wide = (i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64);
+    let (a, .., p): Wide = from_graph_value(row).unwrap();
+    assert_eq!((a, p), (1, 16));
+}
+
+#[test]
+fn test_value_accessors() {
+    assert_eq!(GraphValue::Integer(3).as_integer(), Some(3));
+    assert_eq!(GraphValue::Integer(3).as_double(), None);
+    assert_eq!(GraphValue::String("a".to_string()).as_str(), Some("a"));
+    assert_eq!(GraphValue::Null.as_str(), None);
+    assert_eq!(GraphValue::Boolean(true).as_bool(), Some(true));
+    let array = GraphValue::Array(vec![GraphValue::Double(1.5)]);
+    assert_eq!(array.as_array(), Some(&[GraphValue::Double(1.5)][..]));
+    assert_eq!(array.as_array().unwrap()[0].as_double(), Some(1.5));
+    assert!(array.as_map().is_none());
+    let node = Node::new(1, vec![], Default::default());
+    assert_eq!(GraphValue::Node(node.clone()).as_node(), Some(&node));
+    assert!(GraphValue::Node(node).as_relationship().is_none());
+}
+
+#[test]
+fn test_try_from_value() -> RedisResult<()> {
+    let x: i64 = GraphValue::Integer(5).try_into()?;
+    let y: f64 = GraphValue::Double(1.5).try_into()?;
+    let s: String = GraphValue::String("a".to_string()).try_into()?;
+    let b: bool = GraphValue::Boolean(true).try_into()?;
+    assert_eq!((x, y, s.as_str(), b), (5, 1.5, "a", true));
+    let err = i64::try_from(GraphValue::Double(1.5)).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::TypeMismatch));
+    Ok(())
+}
+
+#[test]
+fn test_value_primitive_eq() {
+    assert_eq!(GraphValue::Integer(5), 5);
+    assert_ne!(GraphValue::Integer(5), 6);
+    assert_ne!(GraphValue::Double(5.0), 5);
+    assert_eq!(GraphValue::Double(1.5), 1.5);
+    assert_ne!(GraphValue::Integer(1), 1.0);
+    assert_ne!(GraphValue::Double(f64::NAN), f64::NAN);
+    assert_eq!(GraphValue::Boolean(true), true);
+    assert_ne!(GraphValue::Integer(1), true);
+    assert_eq!(GraphValue::String("a".to_string()), "a");
+    assert_ne!(GraphValue::String("a".to_string()), "b");
+    assert_ne!(GraphValue::Null, "a");
+}
+
+#[test]
+fn test_from_graph_value_ref_borrows() {
+    let value = GraphValue::String("a long string that would have to be cloned".to_string());
+    let owned = value.as_str().unwrap();
+    // Borrowed strings point into the value itself so no allocation happened
+    let s: &str = from_graph_value_ref(&value).unwrap();
+    assert!(std::ptr::eq(s, owned));
+    let cow: Cow<str> = from_graph_value_ref(&value).unwrap();
+    assert!(matches!(cow, Cow::Borrowed(b) if std::ptr::eq(b, owned)));
+    assert_eq!(from_graph_value_ref::<Option<&str>>(&GraphValue::Null).unwrap(), None);
+    assert_eq!(from_graph_value_ref::<i64>(&GraphValue::Integer(2)).unwrap(), 2);
+    let err = from_graph_value_ref::<&str>(&GraphValue::Integer(2)).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::TypeMismatch));
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn test_uuid_round_trip() {
+    let uuid = uuid::Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    let parameter = Parameter::from(uuid);
+    assert_eq!(parameter, Parameter::String("67e55044-10b1-426f-9247-bb680e5fe0c8".to_string()));
+    let Parameter::String(s) = parameter else { unreachable!() };
+    assert_eq!(from_graph_value::<uuid::Uuid>(GraphValue::String(s)).unwrap(), uuid);
+    let err = from_graph_value::<uuid::Uuid>(GraphValue::String("not a uuid".to_string())).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::TypeMismatch));
+    assert!(from_graph_value::<uuid::Uuid>(GraphValue::Integer(1)).is_err());
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn test_bytes_round_trip() {
+    use crate::GraphBytes;
+
+    let bytes = GraphBytes((0..=255).collect());
+    let Parameter::String(encoded) = Parameter::from(bytes.clone()) else { panic!("bytes are encoded as a string") };
+    assert_eq!(from_graph_value::<GraphBytes>(GraphValue::String(encoded)).unwrap(), bytes);
+    let err = from_graph_value::<GraphBytes>(GraphValue::String("not base64!".to_string())).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::TypeMismatch));
+    assert!(from_graph_value::<GraphBytes>(GraphValue::Integer(1)).is_err());
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn test_bytes_round_trip_live() {
+    use crate::GraphBytes;
+
+    let bytes = GraphBytes(vec![0, 1, 2, 254, 255]);
+    let data: Vec<(GraphBytes,)> = sync_con()
+        .graph_query("test", query!("Return $b", {"b" => bytes.clone()}))
+        .unwrap()
+        .data;
+    assert_eq!(data, vec![(bytes,)]);
+}
+
+#[test]
+fn test_int_bool() {
+    assert_eq!(from_graph_value::<IntBool>(GraphValue::Integer(0)).unwrap(), IntBool(false));
+    assert_eq!(from_graph_value::<IntBool>(GraphValue::Integer(1)).unwrap(), IntBool(true));
+    assert_eq!(from_graph_value::<IntBool>(GraphValue::Boolean(true)).unwrap(), IntBool(true));
+    let err = from_graph_value::<IntBool>(GraphValue::Integer(2)).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::TypeMismatch));
+    assert!(from_graph_value::<IntBool>(GraphValue::Integer(-1)).is_err());
+    assert!(from_graph_value::<bool>(GraphValue::Integer(1)).is_err());
+    assert!(bool::from(IntBool(true)));
+}
+
+#[test]
+fn test_tuple_length_message() {
+    let err = from_graph_value::<(i64, String)>(GraphValue::Array(vec![GraphValue::Integer(1)])).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::LengthMismatch));
+    let detail = err.detail().unwrap();
+    assert!(detail.contains("expected 2 but got 1"), "{detail}");
+}
+
+#[test]
+fn test_duration_from_millis() {
+    assert_eq!(from_graph_value::<Duration>(GraphValue::Integer(1500)).unwrap(), Duration::from_millis(1500));
+    let err = from_graph_value::<Duration>(GraphValue::Integer(-1)).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::IntegerOverflow));
+    let err = from_graph_value::<Duration>(GraphValue::Double(1.5)).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::TypeMismatch));
+}
+
+#[test]
+fn test_integer_overflow() {
+    let err = from_graph_value::<u8>(GraphValue::Integer(300)).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::IntegerOverflow));
+    assert_eq!(err.detail(), Some("Value 300 does not fit into u8"));
+    let err = from_graph_value::<u64>(GraphValue::Integer(-1)).unwrap_err();
+    assert_eq!(err.detail(), Some("Value -1 does not fit into u64"));
+}
+
+#[test]
+fn test_value_type_name() {
+    assert!(GraphValue::Null.is_null());
+    assert!(!GraphValue::Integer(0).is_null());
+    assert_eq!(GraphValue::Null.type_name(), "Null");
+    assert_eq!(GraphValue::Integer(1).type_name(), "Integer");
+    assert_eq!(GraphValue::Node(Node::new(1, vec![], Default::default())).type_name(), "Node");
+    let err = from_graph_value::<bool>(GraphValue::Integer(1)).unwrap_err();
+    assert_eq!(err.detail(), Some("Cant convert Integer to bool: 1"));
+}
+
+#[derive(Debug, PartialEq)]
+struct UserId(i64);
+crate::impl_from_graph_value_newtype!(UserId, i64);
+
+#[derive(Debug, PartialEq)]
+struct Ids(Vec<i64>);
+crate::impl_from_graph_value_newtype!(Ids, Vec<i64>);
+
+#[test]
+fn test_newtype_conversion() {
+    let response = GraphResponse::<UserId>::parse_response(&int_rows(&[4, 5])).unwrap();
+    assert_eq!(response.data, vec![UserId(4), UserId(5)]);
+    assert_eq!(from_graph_value::<(UserId,)>(GraphValue::Array(vec![GraphValue::Integer(1)])).unwrap(), (UserId(1),));
+    assert_eq!(from_graph_value::<Ids>(GraphValue::Array(vec![GraphValue::Integer(1)])).unwrap(), Ids(vec![1]));
+    assert!(from_graph_value::<UserId>(GraphValue::Null).is_err());
+    let ids = GraphValue::Array(vec![GraphValue::Integer(1), GraphValue::Integer(2)]);
+    assert_eq!(from_graph_value::<Ids>(GraphValue::Array(vec![ids])).unwrap(), Ids(vec![1, 2]));
+    // The error of the unwrapped value is returned instead of the one of the array
+    let err = from_graph_value::<UserId>(GraphValue::Array(vec![GraphValue::String("a".to_string())])).unwrap_err();
+    assert_eq!(err.detail(), Some("Cant convert String to i64: \"a\""));
+    let err = from_graph_value::<Ids>(GraphValue::Array(vec![GraphValue::String("a".to_string())])).unwrap_err();
+    assert_eq!(err.detail(), Some("Cant convert String to i64: \"a\""));
+}
+
+#[test]
+fn test_pointer_conversion() {
+    assert_eq!(from_graph_value::<Box<i64>>(GraphValue::Integer(3)).unwrap(), Box::new(3));
+    assert_eq!(*from_graph_value::<std::rc::Rc<String>>(GraphValue::String("a".to_string())).unwrap(), "a");
+    assert_eq!(*from_graph_value::<std::sync::Arc<Option<bool>>>(GraphValue::Null).unwrap(), None);
+    assert!(from_graph_value::<Box<i64>>(GraphValue::Null).is_err());
+}
+
+#[test]
+fn test_map_iter() {
+    let value = Value::Bulk(vec![Value::Int(10), Value::Bulk(vec![
+        Value::Data(b"a".to_vec()),
+        Value::Bulk(vec![Value::Int(3), Value::Int(1)]),
+        Value::Data(b"b".to_vec()),
+        Value::Bulk(vec![Value::Int(3), Value::Int(2)]),
+    ])]);
+    let map: GraphMap = from_graph_value(redis::from_redis_value(&value).unwrap()).unwrap();
+    let mut keys: Vec<&String> = map.keys().collect();
+    keys.sort();
+    assert_eq!(keys, vec!["a", "b"]);
+    assert_eq!(map.values().filter_map(GraphValue::as_integer).sum::<i64>(), 3);
+    let mut sum = 0;
+    for (_, value) in &map {
+        sum += value.as_integer().unwrap();
+    }
+    assert_eq!(sum, 3);
+    assert_eq!(map.iter().count(), 2);
+    let mut entries: Vec<(String, GraphValue)> = map.into_iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(entries, vec![
+        ("a".to_string(), GraphValue::Integer(1)),
+        ("b".to_string(), GraphValue::Integer(2)),
+    ]);
+}
+
+#[test]
+fn test_map_accessors() {
+    let map = GraphMap([("a".to_string(), GraphValue::Integer(1))].into_iter().collect());
+    assert_eq!(map.len(), 1);
+    assert!(!map.is_empty());
+    assert!(map.contains_key("a"));
+    assert!(!map.contains_key("b"));
+    assert_eq!(map.get_ref("a"), Some(&GraphValue::Integer(1)));
+    assert_eq!(map.get_ref("b"), None);
+    assert_eq!(map.get::<i64>("a").unwrap(), Some(1));
+    assert!(GraphMap(HashMap::new()).is_empty());
+}
+
+#[test]
+fn test_borrowed_getters() {
+    let map = GraphMap([
+        ("name".to_string(), GraphValue::String("a".to_string())),
+        ("nested".to_string(), GraphValue::Array(vec![GraphValue::Integer(1); 3])),
+    ].into_iter().collect());
+    assert_eq!(map.get_borrowed::<&str>("name").unwrap(), Some("a"));
+    assert_eq!(map.get_borrowed::<&[GraphValue]>("nested").unwrap().map(<[_]>::len), Some(3));
+    assert_eq!(map.get_borrowed::<&str>("missing").unwrap(), None);
+    assert!(map.get_borrowed::<&str>("nested").is_err());
+    let node = Node::new(1, vec![], [(3, GraphValue::String("b".to_string())), (5, GraphValue::Null)].into_iter().collect());
+    assert_eq!(node.get_property_ref_by_label_id::<&str>(3).unwrap(), Some("b"));
+    assert_eq!(node.get_property_ref_by_label_id::<&str>(5).unwrap(), None);
+    assert_eq!(node.get_property_ref_by_label_id::<&str>(4).unwrap(), None);
+    assert_eq!(node.get_property_ref_by_index::<&str>(0).unwrap(), "b");
+    assert_eq!(node.get_property_ref_by_index::<Option<i64>>(1).unwrap(), None);
+}
+
+#[test]
+fn test_map_conversion() {
+    let value = GraphValue::Map(GraphMap([
+        ("c".to_string(), GraphValue::Integer(3)),
+        ("a".to_string(), GraphValue::Integer(1)),
+        ("b".to_string(), GraphValue::Integer(2)),
+    ].into_iter().collect()));
+    let sorted: BTreeMap<String, i64> = from_graph_value(value.clone()).unwrap();
+    assert_eq!(sorted.keys().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+    assert_eq!(sorted.values().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    let map: HashMap<String, i64> = from_graph_value(value).unwrap();
+    assert_eq!(map["b"], 2);
+    assert!(from_graph_value::<BTreeMap<String, String>>(GraphValue::Map(GraphMap(
+        [("a".to_string(), GraphValue::Integer(1))].into_iter().collect()
+    ))).is_err());
+}
+
+#[test]
+fn test_resolve_labels() {
+    let labels = vec!["User".to_string(), "Team".to_string(), "Admin".to_string()];
+    let node = Node::new(0, vec![0, 2], Default::default());
+    assert_eq!(node.resolve_labels(&labels), vec!["User", "Admin"]);
+    let node = Node::new(0, vec![1, 3, -1], Default::default());
+    assert_eq!(node.resolve_labels(&labels), vec!["Team"]);
+}
+
+#[test]
+fn test_get_property_by_name() {
+    let con = &mut sync_con();
+    con.graph_query_void("test_property_name", query!("Create (:User {name: 'a', age: 5})")).unwrap();
+    let (node,): (Node,) = con
+        .graph_query("test_property_name", query!("Match (u:User) Return u"))
+        .unwrap()
+        .data
+        .remove(0);
+    let keys = con.property_keys("test_property_name").unwrap();
+    con.graph_delete("test_property_name").unwrap();
+    assert_eq!(node.get_property_by_name::<i64>("age", &keys).unwrap(), Some(5));
+    assert_eq!(node.get_property_by_name::<String>("name", &keys).unwrap(), Some("a".to_string()));
+    assert_eq!(node.get_property_by_name::<String>("unknown", &keys).unwrap(), None);
+}
+
+#[test]
+fn test_properties_by_name() {
+    let node = Node::new(0, vec![], [
+        (1, GraphValue::Integer(5)),
+        (0, GraphValue::String("a".to_string())),
+        (7, GraphValue::Null),
+    ].into_iter().collect());
+    let keys = vec!["name".to_string(), "age".to_string()];
+    let properties = node.properties_by_name(&keys);
+    assert_eq!(properties.len(), 2);
+    assert_eq!(properties["name"], GraphValue::String("a".to_string()));
+    assert_eq!(properties["age"], GraphValue::Integer(5));
+}
+
+/// Connection answering `db.labels`, `db.propertyKeys` and `db.relationshipTypes` with the given names
+fn schema_con(labels: &'static [&'static str], property_keys: &'static [&'static str], relationship_types: &'static [&'static str]) -> MockConnection {
+    MockConnection::new(move |cmd| Ok(if cmd.contains("db.labels") {
+        string_rows(labels)
+    } else if cmd.contains("db.propertyKeys") {
+        string_rows(property_keys)
+    } else {
+        string_rows(relationship_types)
+    }))
+}
+
+#[test]
+fn test_graph_schema() {
+    let mut con = schema_con(&["User", "Team"], &["name"], &["rides"]);
+    let mut schema = GraphSchema::load(&mut con, "test").unwrap();
+    assert_eq!(con.commands.len(), 3);
+    assert_eq!(schema.label_name(1), Some("Team"));
+    assert_eq!(schema.label_name(2), None);
+    assert_eq!(schema.property_name(0), Some("name"));
+    assert_eq!(schema.relationship_name(0), Some("rides"));
+    assert_eq!(schema.relationship_name(-1), None);
+    assert_eq!(con.commands.len(), 3);
+    schema.refresh(&mut con).unwrap();
+    assert_eq!(con.commands.len(), 6);
+}
+
+#[test]
+fn test_schema_reverse_lookup() {
+    let mut con = schema_con(&["User", "Team"], &["name", "age"], &["rides"]);
+    let schema = GraphSchema::load(&mut con, "test").unwrap();
+    assert_eq!(schema.label_id("Team"), Some(1));
+    assert_eq!(schema.label_id("Rider"), None);
+    assert_eq!(schema.property_key_id("age"), Some(1));
+    assert_eq!(schema.relationship_type_id("rides"), Some(0));
+    let id = schema.label_id("User").unwrap();
+    assert_eq!(schema.label_name(id), Some("User"));
+}
+
+#[test]
+fn test_schema_names() {
+    let mut con = schema_con(&["User", "Team", "Admin"], &["name"], &["member_of"]);
+    let schema = GraphSchema::load(&mut con, "test").unwrap();
+    let node = Node::new(0, vec![2, 0, 5], Default::default());
+    assert_eq!(node.labels(&schema), vec!["Admin", "User"]);
+    let relationship = Relationship::new(0, 0, 0, 1, Default::default());
+    assert_eq!(relationship.type_name(&schema), Some("member_of"));
+    let relationship = Relationship::new(0, 3, 0, 1, Default::default());
+    assert_eq!(relationship.type_name(&schema), None);
+}
+
+/// Compact graph response with a single integer column
+fn int_rows(rows: &[i64]) -> Value {
+    mock_response(&["value"], rows.iter().map(|row| vec![mock_integer(*row)]).collect(), &["Cached execution: 0"])
+}
+
+#[test]
+fn test_pipeline() {
+    let mut con = MockConnection::new(|_| Ok(Value::Bulk(vec![int_rows(&[1]), int_rows(&[2]), int_rows(&[3])])));
+    let responses: Vec<GraphResponse<(i64,)>> = GraphPipeline::new()
+        .query("test", query!("Return 1"))
+        .query("test", query!("Return 2", true))
+        .query("other", query!("Return 3"))
+        .execute(&mut con)
+        .unwrap();
+    assert_eq!(con.commands.len(), 1);
+    assert!(con.commands[0].contains("GRAPH.RO_QUERY"));
+    assert_eq!(con.commands[0].matches("GRAPH.QUERY").count(), 2);
+    let data: Vec<Vec<(i64,)>> = responses.into_iter().map(|res| res.data).collect();
+    assert_eq!(data, vec![vec![(1,)], vec![(2,)], vec![(3,)]]);
+}
+
+#[cfg(feature = "tokio-comp")]
+#[test]
+fn test_pipeline_async() {
+    let mut con = MockConnection::new(|_| Ok(Value::Bulk(vec![int_rows(&[1]), int_rows(&[2]), int_rows(&[3])])));
+    let responses: Vec<GraphResponse<(i64,)>> = tokio_runtime().block_on(
+        GraphPipeline::new()
+            .query("test", query!("Return 1", true))
+            .query("test", query!("Return 2", true))
+            .query("other", query!("Return 3", true))
+            .execute_async(&mut con),
+    ).unwrap();
+    assert_eq!(con.commands.len(), 1);
+    assert_eq!(con.commands[0].matches("GRAPH.RO_QUERY").count(), 3);
+    let data: Vec<Vec<(i64,)>> = responses.into_iter().map(|response| response.data).collect();
+    assert_eq!(data, vec![vec![(1,)], vec![(2,)], vec![(3,)]]);
+}
+
+#[test]
+fn test_query_timeout_argument() {
+    let mut con = MockConnection::new(|_| Ok(int_rows(&[1])));
+    con.graph_query_void("test", query!("Return 1")).unwrap();
+    con.graph_query_void("test", query!("Return 1", timeout = 42)).unwrap();
+    assert!(!con.commands[0].contains("TIMEOUT"));
+    assert!(con.commands[1].contains("TIMEOUT\r\n$2\r\n42\r\n"));
+}
+
+#[test]
+fn test_graph_execute() {
+    let con = &mut sync_con();
+    let stats = con.graph_execute("test_execute", query!("Create (:User {name: 'a'}), (:User)")).unwrap();
+    con.graph_delete("test_execute").unwrap();
+    assert_eq!(stats.nodes_created, 2);
+    assert_eq!(stats.properties_set, 1);
+}
+
+#[test]
+fn test_query_raw() {
+    let mut con = MockConnection::new(|_| Ok(int_rows(&[1])));
+    let expected: GraphResponse<(i64,)> = con.graph_query("test", query!("Return 1")).unwrap();
+    let raw: GraphResponse<(i64,)> = con.graph_query_raw("test", "Return 1", &[]).unwrap();
+    assert_eq!(con.commands[0], con.commands[1]);
+    assert_eq!(raw.data, expected.data);
+    con.graph_query_raw::<(i64,)>("test", "Return 1", &["TIMEOUT", "5"]).unwrap();
+    assert!(con.commands[2].ends_with("$9\r\n--compact\r\n$7\r\nTIMEOUT\r\n$1\r\n5\r\n"));
+}
+
+#[test]
+fn test_query_timeout() {
+    let result = sync_con().graph_query_void(
+        "test",
+        query!("Unwind range(0, 100000000) As x Return count(x)", timeout = 1),
+    );
+    assert!(result.unwrap_err().to_string().contains("Query timed out"));
+}
+
+#[test]
+fn test_query_scalar() {
+    let mut con = MockConnection::new(|_| Ok(int_rows(&[3, 4])));
+    assert_eq!(con.graph_query_scalar::<_, i64>("test", query!("Match (n) Return count(n)")).unwrap(), Some(3));
+    let mut con = MockConnection::new(|_| Ok(int_rows(&[])));
+    assert_eq!(con.graph_query_scalar::<_, i64>("test", query!("Match (n) Return n.age")).unwrap(), None);
+}
+
+#[test]
+fn test_query_one() {
+    let mut con = MockConnection::new(|_| Ok(int_rows(&[])));
+    assert_eq!(con.graph_query_one::<_, (i64,)>("test", query!("Return 1 Limit 0")).unwrap(), None);
+    let mut con = MockConnection::new(|_| Ok(int_rows(&[1])));
+    assert_eq!(con.graph_query_one::<_, (i64,)>("test", query!("Return 1")).unwrap(), Some((1,)));
+    let mut con = MockConnection::new(|_| Ok(int_rows(&[1, 2])));
+    let err = con.graph_query_one::<_, (i64,)>("test", query!("Unwind [1, 2] As x Return x")).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::LengthMismatch));
+}
+
+#[cfg(feature = "tokio-comp")]
+#[test]
+fn test_query_scalar_async() {
+    use crate::AsyncGraphCommands;
+    let count: Option<i64> = tokio_runtime().block_on(async move {
+        async_con().await.graph_query_scalar("test", query!("Unwind range(1, 3) As x Return count(x)")).await.unwrap()
+    });
+    assert_eq!(count, Some(3));
+}
+
+#[test]
+fn test_infer_read_only() {
+    assert!(query!("Match (n:User) Return n.name").infer_read_only().read_only);
+    assert!(!query!("Match (n:User) Set n.name = 'a' Return n").infer_read_only().read_only);
+    assert!(!query!("MATCH (n) DETACH DELETE n").infer_read_only().read_only);
+    assert!(!query!("Match (a) cReAtE (a)-[:R]->(:B)", true).infer_read_only().read_only);
+    assert!(!query!("Call db.labels()").infer_read_only().read_only);
+    assert!(query!("Match (n:Settings) Return n.offset").infer_read_only().read_only);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    let node = Node::new(1, vec![0], [(0, GraphValue::String("a".to_string()))].into_iter().collect());
+    let rel = Relationship::new(2, 0, 1, 3, Default::default());
+    let value = GraphValue::Array(vec![
+        GraphValue::Integer(1),
+        GraphValue::Null,
+        GraphValue::Map(GraphMap([
+            ("point".to_string(), GraphValue::Point(GeoPoint { latitude: 1.5, longitude: 2.5 })),
+            ("path".to_string(), GraphValue::Path(GraphPath {
+                nodes: vec![node.clone(), Node::new(3, vec![], Default::default())],
+                relationships: vec![rel.clone()],
+            })),
+        ].into_iter().collect())),
+        GraphValue::Node(node),
+        GraphValue::Relation(rel),
+    ]);
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(serde_json::from_str::<GraphValue>(&json).unwrap(), value);
+    assert_eq!(
+        serde_json::to_value(GraphValue::Array(vec![GraphValue::Integer(1), GraphValue::Null])).unwrap(),
+        serde_json::json!({"type": "Array", "value": [{"type": "Integer", "value": 1}, {"type": "Null"}]})
+    );
+    assert!(serde_json::to_string(&GraphValue::Unknown(Value::Nil)).is_err());
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_json_conversion() {
+    let value = GraphValue::Array(vec![GraphValue::Map(GraphMap([
+        ("a".to_string(), GraphValue::Array(vec![
+            GraphValue::Integer(1),
+            GraphValue::Double(1.5),
+            GraphValue::String("b".to_string()),
+            GraphValue::Boolean(true),
+            GraphValue::Null,
+        ])),
+        ("node".to_string(), GraphValue::Node(Node::new(
+            1,
+            vec![0],
+            [(2, GraphValue::Integer(3))].into_iter().collect(),
+        ))),
+    ].into_iter().collect()))]);
+    let (json,): (serde_json::Value,) = from_graph_value(value).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "a": [1, 1.5, "b", true, null],
+            "node": {"id": 1, "label_ids": [0], "properties": {"2": 3}}
+        })
+    );
+    assert!(from_graph_value::<serde_json::Value>(GraphValue::Unknown(Value::Nil)).is_err());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_conversion() {
+    use chrono::{DateTime, NaiveDateTime, Utc};
+
+    let time: DateTime<Utc> = from_graph_value(GraphValue::Integer(1_600_000_000)).unwrap();
+    assert_eq!(time.to_rfc3339(), "2020-09-13T12:26:40+00:00");
+    let time: NaiveDateTime = from_graph_value(GraphValue::Double(1.5)).unwrap();
+    assert_eq!(time.and_utc().timestamp_millis(), 1500);
+    assert!(from_graph_value::<DateTime<Utc>>(GraphValue::String("2020".to_string())).is_err());
+}
+
+#[cfg(feature = "deadpool-comp")]
+mod deadpool {
+    use crate::{deadpool_redis::{Config, Connection, Runtime}, query, AsyncGraphCommands};
+
+    use super::tokio_runtime;
+
+    fn assert_async_commands<T: AsyncGraphCommands>() {}
+
+    #[test]
+    fn test_pooled_connection_implements_commands() {
+        assert_async_commands::<Connection>();
+    }
+
+    #[test]
+    fn test_pooled_query() {
+        let data: Vec<(i64,)> = tokio_runtime().block_on(async move {
+            let pool = Config::from_url("redis://localhost:6379/")
+                .create_pool(Some(Runtime::Tokio1))
+                .unwrap();
+            let mut con = pool.get().await.unwrap();
+            con.graph_query("test", query!("Return 1")).await.unwrap().data
+        });
+        assert_eq!(data, vec![(1,)]);
+    }
+}
+
+#[cfg(feature = "connection-manager")]
+mod connection_manager {
+    use redis::aio::ConnectionManager;
+
+    use crate::{query, AsyncGraphCommands};
+
+    use super::{get_client, tokio_runtime};
+
+    fn assert_async_commands<T: AsyncGraphCommands>() {}
+
+    #[test]
+    fn test_connection_manager_implements_commands() {
+        assert_async_commands::<ConnectionManager>();
+    }
+
+    #[test]
+    fn test_connection_manager_query() {
+        let data: Vec<(i64,)> = tokio_runtime().block_on(async move {
+            let manager = ConnectionManager::new(get_client()).await.unwrap();
+            manager.clone().graph_query("test", query!("Return 1")).await.unwrap().data
+        });
+        assert_eq!(data, vec![(1,)]);
+    }
+}
+
+#[cfg(feature = "tokio-comp")]
+mod stream {
+    use futures_util::StreamExt;
+    use redis::FromRedisValue;
+
+    use crate::{query, AsyncGraphCommands, GraphRowStream};
+
+    use super::{async_con, int_rows, tokio_runtime};
+
+    #[test]
+    fn test_row_stream_parse() {
+        let rows: Vec<i64> = (0..500).collect();
+        let mut stream = GraphRowStream::<(i64,)>::from_redis_value(&int_rows(&rows)).unwrap();
+        assert_eq!(stream.remaining(), 500);
+        let sum = tokio_runtime().block_on(async move {
+            let mut sum = 0;
+            while let Some(row) = stream.next().await {
+                sum += row.unwrap().0;
+            }
+            sum
+        });
+        assert_eq!(sum, rows.iter().sum::<i64>());
+    }
+
+    #[test]
+    fn test_graph_query_stream() {
+        let count = tokio_runtime().block_on(async move {
+            let mut con = async_con().await;
+            let stream = con
+                .graph_query_stream::<_, (i64,)>("test", query!("Unwind range(1, 300) As x Return x"))
+                .await
+                .unwrap();
+            stream.map(|row| row.unwrap().0).collect::<Vec<_>>().await
+        });
+        assert_eq!(count, (1..=300).collect::<Vec<i64>>());
+    }
+}
+
+#[cfg(feature = "tls-rustls")]
+mod tls {
+    use crate::{query, GraphCommands};
+
+    fn get_tls_client() -> redis::Client {
+        redis::Client::open("rediss://localhost:6380/").unwrap()
+    }
+
+    #[test]
+    fn test_tls_query() {
+        let data: Vec<(i64,)> = get_tls_client()
+            .get_connection()
+            .unwrap()
+            .graph_query("test", query!("Return 1"))
+            .unwrap()
+            .data;
+        assert_eq!(data, vec![(1,)]);
+    }
+
+    #[cfg(feature = "tokio-rustls-comp")]
+    #[test]
+    fn test_tls_query_async() {
+        use crate::AsyncGraphCommands;
+
+        let data: Vec<(i64,)> = super::tokio_runtime().block_on(async move {
+            let mut con = get_tls_client().get_async_connection().await.unwrap();
+            con.graph_query("test", query!("Return 1")).await.unwrap().data
+        });
+        assert_eq!(data, vec![(1,)]);
+    }
+}
+
+#[cfg(feature = "cluster")]
+mod cluster {
+    use redis::cluster::ClusterClient;
+
+    use crate::{query, GraphCommands};
+
+    #[test]
+    fn test_cluster_read_only_query() {
+        let client = ClusterClient::builder(vec!["redis://localhost:7000/"])
+            .read_from_replicas()
+            .build()
+            .unwrap();
+        let mut con = client.get_connection().unwrap();
+        let data: Vec<(i64,)> = con.graph_query("test", query!("Return 1", true)).unwrap().data;
+        assert_eq!(data, vec![(1,)]);
+    }
+}
+
+#[cfg(feature = "tokio-comp")]
+mod cancellation {
+    use futures_util::FutureExt;
+
+    use redis::{ErrorKind, RedisFuture, Value};
+
+    use crate::{query, testing::*, AsyncGraphCommands, CancelSafe};
+
+    use super::{async_con, get_client, tokio_runtime};
+
+    #[test]
+    fn test_reuse_multiplexed_after_drop() {
+        let data: Vec<(i64,)> = tokio_runtime().block_on(async move {
+            let mut con = get_client().get_multiplexed_async_connection().await.unwrap();
+            let slow = con
+                .graph_query::<_, (i64,)>("test", query!("Unwind range(1, 1000000) As x Return count(x)"))
+                .now_or_never();
+            assert!(slow.is_none());
+            con.graph_query("test", query!("Return 1")).await.unwrap().data
+        });
+        assert_eq!(data, vec![(1,)]);
+    }
+
+    #[test]
+    fn test_cancel_safe_after_drop() {
+        let (poisoned, result) = tokio_runtime().block_on(async move {
+            let mut con = CancelSafe::new(async_con().await);
+            let slow = con
+                .graph_query::<_, (i64,)>("test", query!("Unwind range(1, 1000000) As x Return count(x)"))
+                .now_or_never();
+            assert!(slow.is_none());
+            let poisoned = con.is_poisoned();
+            (poisoned, con.graph_query::<_, (i64,)>("test", query!("Return 1")).await)
+        });
+        // The reply of the dropped query must never be returned as the reply of the next one
+        match result {
+            Ok(response) => {
+                assert!(!poisoned);
+                assert_eq!(response.data, vec![(1,)]);
+            }
+            Err(err) => {
+                assert!(poisoned);
+                assert_eq!(err.kind(), ErrorKind::ClientError);
+            }
+        }
+    }
+
+    /// Connection which never replies to the first `pending` requests
+    struct PendingConnection {
+        pending: usize,
+    }
+
+    impl redis::aio::ConnectionLike for PendingConnection {
+        fn req_packed_command<'a>(&'a mut self, _cmd: &'a redis::Cmd) -> RedisFuture<'a, Value> {
+            if self.pending > 0 {
+                self.pending -= 1;
+                return Box::pin(std::future::pending());
+            }
+            Box::pin(async move { Ok(mock_response(&["x"], vec![vec![mock_integer(1)]], &[])) })
+        }
+
+        fn req_packed_commands<'a>(&'a mut self, _cmd: &'a redis::Pipeline, _offset: usize, _count: usize) -> RedisFuture<'a, Vec<Value>> {
+            Box::pin(std::future::pending())
+        }
+
+        fn get_db(&self) -> i64 {
+            0
+        }
+    }
+
+    #[test]
+    fn test_cancel_safe_poisons_connection() {
+        tokio_runtime().block_on(async move {
+            let mut con = CancelSafe::new(PendingConnection { pending: 0 });
+            let data = con.graph_query::<_, (i64,)>("test", query!("Return 1")).await.unwrap().data;
+            assert_eq!(data, vec![(1,)]);
+            assert!(!con.is_poisoned());
+
+            let mut con = CancelSafe::new(PendingConnection { pending: 1 });
+            assert!(con.graph_query::<_, (i64,)>("test", query!("Return 1")).now_or_never().is_none());
+            assert!(con.is_poisoned());
+            // The wrapped connection would answer now but the reply could belong to the dropped query
+            let err = con.graph_query::<_, (i64,)>("test", query!("Return 1")).await.unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ClientError);
+            assert!(con.is_poisoned());
+        });
+    }
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use crate::{
+        from_graph_value, query, testing::*, FromGraphMap, FromGraphValue, GraphCommands, GraphError, GraphMap, GraphResponse, GraphValue,
+        Node, PropertyAccess,
+    };
+
+    use super::sync_con;
+
+    #[derive(FromGraphValue, Debug, PartialEq)]
+    struct Row {
+        id: i64,
+        name: String,
+    }
+
+    #[derive(FromGraphValue, Debug, PartialEq)]
+    struct Reordered(#[graph(index = 1)] String, #[graph(index = 0)] i64);
+
+    #[test]
+    fn test_derive_from_graph_value() {
+        let value = GraphValue::Array(vec![GraphValue::Integer(1), GraphValue::String("a".to_string())]);
+        assert_eq!(
+            from_graph_value::<Row>(value.clone()).unwrap(),
+            Row { id: 1, name: "a".to_string() }
+        );
+        assert_eq!(
+            from_graph_value::<Reordered>(value).unwrap(),
+            Reordered("a".to_string(), 1)
+        );
+        assert!(from_graph_value::<Row>(GraphValue::Array(vec![GraphValue::Integer(1)])).is_err());
+        assert!(from_graph_value::<Row>(GraphValue::Integer(1)).is_err());
+    }
+
+    #[test]
+    fn test_derive_query() {
+        let data: Vec<Row> = sync_con().graph_query("test", query!("Return 1, 'a'")).unwrap().data;
+        assert_eq!(data, vec![Row { id: 1, name: "a".to_string() }]);
+    }
+
+    #[derive(FromGraphMap, Debug, PartialEq)]
+    struct Person {
+        name: String,
+        #[graph(rename = "years")]
+        age: i64,
+        email: Option<String>,
+    }
+
+    #[test]
+    fn test_derive_from_graph_map() {
+        let reply = mock_response(
+            &["person"],
+            vec![vec![mock_map(vec![("name", mock_string("a")), ("years", mock_integer(5))])]],
+            &[],
+        );
+        let response: GraphResponse<(Person,)> = redis::from_redis_value(&reply).unwrap();
+        assert_eq!(response.data, vec![(Person { name: "a".to_string(), age: 5, email: None },)]);
+        let missing = GraphValue::Map(GraphMap([("name".to_string(), GraphValue::String("a".to_string()))].into_iter().collect()));
+        let err = from_graph_value::<Person>(missing).unwrap_err();
+        assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::MissingColumn));
+        assert!(err.detail().unwrap().contains("\"years\""));
+        assert!(from_graph_value::<Person>(GraphValue::Integer(1)).is_err());
+    }
+
+    #[test]
+    fn test_node_into_struct() {
+        let key_map = vec!["email".to_string(), "name".to_string(), "years".to_string()];
+        let node: Node = from_graph_value(redis::from_redis_value(&mock_node(
+            1,
+            &[0],
+            vec![(1, mock_string("a")), (2, mock_integer(5))],
+        )).unwrap()).unwrap();
+        let person: Person = node.properties_into(&key_map).unwrap();
+        assert_eq!(person, Person { name: "a".to_string(), age: 5, email: None });
+        assert!(node.properties_into::<Person>(&key_map[..1]).is_err());
+    }
+
+    #[test]
+    fn test_derive_map_query() {
+        let data: Vec<(Person,)> = sync_con()
+            .graph_query("test", query!("Return {name: 'a', years: 5, email: 'a@b.c'}"))
+            .unwrap()
+            .data;
+        assert_eq!(data, vec![(Person { name: "a".to_string(), age: 5, email: Some("a@b.c".to_string()) },)]);
+    }
+}
+
+#[test]
+fn test_slowlog_parse() {
+    let mut con = MockConnection::new(|_| Ok(Value::Bulk(vec![Value::Bulk(vec![
+        Value::Data(b"1581932396".to_vec()),
+        Value::Data(b"GRAPH.QUERY".to_vec()),
+        Value::Data(b"Match (n) Return n".to_vec()),
+        Value::Data(b"0.288".to_vec()),
+    ])])));
+    assert_eq!(
+        con.slowlog("test").unwrap(),
+        vec![SlowlogEntry {
+            timestamp: 1581932396,
+            command: "GRAPH.QUERY".to_string(),
+            query: "Match (n) Return n".to_string(),
+            latency: 0.288,
+        }]
+    );
+}
+
+#[test]
+fn test_slowlog() {
+    let con = &mut sync_con();
+    con.graph_query_void("test_slowlog", query!("Unwind range(0, 100000) As x Return count(x)")).unwrap();
+    let entries = con.slowlog("test_slowlog").unwrap();
+    con.slowlog_reset("test_slowlog").unwrap();
+    con.graph_delete("test_slowlog").unwrap();
+    assert!(!entries.is_empty());
+    assert!(entries[0].latency > 0.0);
+}
+
+#[test]
+fn test_list_graphs() {
+    let con = &mut sync_con();
+    con.graph_query_void("test_list", query!("Create (:User)")).unwrap();
+    let graphs = con.list_graphs().unwrap();
+    con.graph_delete("test_list").unwrap();
+    assert!(graphs.contains(&"test_list".to_string()));
+}
+
+#[test]
+fn test_create_nodes_query() {
+    let mut con = MockConnection::new(|_| Ok(Value::Bulk(vec![Value::Bulk(vec![
+        Value::Data(b"Labels added: 1".to_vec()),
+        Value::Data(b"Nodes created: 2".to_vec()),
+        Value::Data(b"Properties set: 2".to_vec()),
+    ])])));
+    let rows: Vec<HashMap<String, i64>> = (1..=2).map(|id| [("id".to_string(), id)].into_iter().collect()).collect();
+    let stats = con.create_nodes("test", "User`)", rows).unwrap();
+    assert_eq!(stats.nodes_created, 2);
+    assert!(con.commands[0].contains("CYPHER batch=[{id: 1},{id: 2}] UNWIND $batch AS row CREATE (n:`User``)`) SET n = row"));
+}
+
+#[test]
+fn test_create_nodes() {
+    let con = &mut sync_con();
+    let rows: Vec<HashMap<String, i64>> = (0..100).map(|id| [("id".to_string(), id)].into_iter().collect()).collect();
+    let stats = con.create_nodes("test_create_nodes", "User", rows).unwrap();
+    let count = con.graph_query_scalar::<_, i64>("test_create_nodes", query!("Match (u:User) Return count(u)", true)).unwrap();
+    con.graph_delete("test_create_nodes").unwrap();
+    assert_eq!(stats.nodes_created, 100);
+    assert_eq!(count, Some(100));
+}
+
+#[test]
+fn test_graph_exists_command() {
+    let mut con = MockConnection::new(|cmd| Ok(Value::Int(cmd.contains("present") as i64)));
+    assert!(con.graph_exists("present").unwrap());
+    assert!(!con.graph_exists("missing").unwrap());
+    assert!(con.commands[0].contains("EXISTS"));
+}
+
+#[test]
+fn test_graph_exists() {
+    let con = &mut sync_con();
+    con.graph_query_void("test_exists", query!("Create (:User)")).unwrap();
+    let exists = con.graph_exists("test_exists").unwrap();
+    con.graph_delete("test_exists").unwrap();
+    assert!(exists);
+    assert!(!con.graph_exists("test_exists").unwrap());
+}
+
+#[test]
+fn test_copy_graph() {
+    let con = &mut sync_con();
+    con.graph_query_void("test_copy_src", query!("Create (:User {name: 'a'}), (:User {name: 'b'})")).unwrap();
+    con.copy_graph("test_copy_src", "test_copy_dest").unwrap();
+    let query = "Match (u:User) Return u.name Order By u.name";
+    let src: Vec<(String,)> = con.graph_query("test_copy_src", query!(query, true)).unwrap().data;
+    let dest: Vec<(String,)> = con.graph_query("test_copy_dest", query!(query, true)).unwrap().data;
+    assert!(con.copy_graph("test_copy_src", "test_copy_dest").is_err());
+    con.graph_delete("test_copy_src").unwrap();
+    con.graph_delete("test_copy_dest").unwrap();
+    assert_eq!(src, dest);
+}
+
+#[test]
+fn test_mappings() {
+    let con = &mut sync_con();
+    con.labels("test").unwrap();
+    con.relationship_types("test").unwrap();
+    con.property_keys("test").unwrap();
+}
+
+#[test]
+fn test_error_kinds() {
+    let err = from_graph_value::<i64>(GraphValue::String("1".to_string())).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::TypeMismatch));
+    let err = from_graph_value::<(i64, i64)>(GraphValue::Array(vec![GraphValue::Integer(1)])).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::LengthMismatch));
+    let err = GraphResponse::<(i64,)>::parse_response(&Value::Int(1)).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::InvalidResponse));
+    assert_eq!(GraphError::from_redis_error(&crate::create_rediserror("other")), None);
+}
+
+#[test]
+fn test_malformed_header() {
+    let response = Value::Bulk(vec![
+        Value::Bulk(vec![Value::Data(b"x".to_vec())]),
+        Value::Bulk(vec![]),
+        Value::Bulk(vec![]),
+    ]);
+    assert!(GraphResponse::<(i64,)>::parse_response(&response).is_err());
+}
+
+#[test]
+fn test_indexes_parse() {
+    let reply = mock_response(
+        &["label", "properties", "types", "entitytype", "status"],
+        vec![vec![
+            mock_string("User"),
+            mock_array(vec![mock_string("name")]),
+            mock_map(vec![("name", mock_array(vec![mock_string("RANGE")]))]),
+            mock_string("NODE"),
+            mock_string("OPERATIONAL"),
+        ]],
+        &[],
+    );
+    let mut con = MockConnection::new(move |_| Ok(reply.clone()));
+    let indexes = con.indexes("test").unwrap();
+    assert!(con.commands[0].contains("db.indexes()"));
+    assert_eq!(indexes.len(), 1);
+    assert_eq!(indexes[0].label, "User");
+    assert_eq!(indexes[0].properties, vec!["name"]);
+    assert_eq!(indexes[0].entity_type, "NODE");
+    assert_eq!(indexes[0].status, "OPERATIONAL");
+}
+
+#[test]
+fn test_index_queries() {
+    let mut con = MockConnection::new(|cmd| Ok(mock_response(&[], vec![], &[if cmd.contains("CREATE INDEX") {
+        "Indices created: 1"
+    } else {
+        "Indices deleted: 1"
+    }])));
+    assert_eq!(con.create_index("test", "User", "name").unwrap().indices_created, 1);
+    assert_eq!(con.drop_index("test", "User", "name").unwrap().indices_deleted, 1);
+    assert!(con.commands[0].contains("CREATE INDEX FOR (n:User) ON (n.name)"));
+    assert!(con.commands[1].contains("DROP INDEX ON :User(name)"));
+    con.create_index("test", "User) DELETE (n", "a b").unwrap();
+    assert!(con.commands[2].contains("CREATE INDEX FOR (n:`User) DELETE (n`) ON (n.`a b`)"));
+}
+
+#[test]
+fn test_create_drop_index() {
+    let con = &mut sync_con();
+    assert_eq!(con.create_index("test", "IndexedTeam", "name").unwrap().indices_created, 1);
+    assert_eq!(con.drop_index("test", "IndexedTeam", "name").unwrap().indices_deleted, 1);
+}
+
+#[test]
+fn test_indexes() {
+    let con = &mut sync_con();
+    con.graph_query_void("test", query!("CREATE INDEX FOR (n:IndexedUser) ON (n.name)")).unwrap();
+    let indexes = con.indexes("test").unwrap();
+    assert!(indexes.iter().any(|index| index.label == "IndexedUser" && index.properties == vec!["name"]));
+    con.graph_query_void("test", query!("DROP INDEX ON :IndexedUser(name)")).unwrap();
+}
+
+#[test]
+fn test_graph_version_parse() {
+    let data = |s: &str| Value::Data(s.as_bytes().to_vec());
+    let module = move |name: &str, ver: i64| Value::Bulk(vec![
+        data("name"), data(name), data("ver"), Value::Int(ver), data("path"), data("/redisgraph.so"), data("args"), Value::Bulk(vec![]),
+    ]);
+    let modules = Value::Bulk(vec![module("search", 20609), module("graph", 21210)]);
+    let mut con = MockConnection::new(move |_| Ok(modules.clone()));
+    assert_eq!(con.graph_version().unwrap(), "2.12.10");
+    assert_eq!(con.commands.len(), 1);
+    assert!(con.commands[0].contains("MODULE"));
+    let modules = Value::Bulk(vec![module("search", 20609)]);
+    let mut con = MockConnection::new(move |_| Ok(modules.clone()));
+    let err = con.graph_version().unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::InvalidResponse));
+}
+
+#[test]
+fn test_graph_version() {
+    assert!(!sync_con().graph_version().unwrap().is_empty());
+}
+
+#[test]
+fn test_query_lenient() {
+    let broken = Value::Bulk(vec![Value::Int(5), Value::Data(b"not a double".to_vec())]);
+    let reply = mock_response(&["a", "b", "c"], vec![vec![mock_integer(1), mock_string("x"), broken.clone()]], &[]);
+    let mut con = MockConnection::new(move |_| Ok(reply.clone()));
+    assert!(con.graph_query::<_, (i64, i64, f64)>("test", query!("Return 1, 'x', 1.5")).is_err());
+    let response = con.graph_query_lenient("test", query!("Return 1, 'x', 1.5")).unwrap();
+    assert_eq!(response.header_names(), vec!["a", "b", "c"]);
+    assert_eq!(response.data, vec![GraphValue::Array(vec![
+        GraphValue::Integer(1),
+        GraphValue::String("x".to_string()),
+        GraphValue::Unknown(broken),
+    ])]);
+}
+
+#[test]
+fn test_special_doubles() {
+    let double = |literal: &str| -> f64 {
+        let value = Value::Bulk(vec![Value::Int(5), Value::Data(literal.as_bytes().to_vec())]);
+        redis::from_redis_value::<GraphValue>(&value).unwrap().as_double().unwrap()
+    };
+    assert_eq!(double("inf"), f64::INFINITY);
+    assert_eq!(double("-inf"), f64::NEG_INFINITY);
+    assert!(double("nan").is_nan());
+    assert!(double("-nan").is_nan());
+    assert_eq!(double("1.5"), 1.5);
+}
+
+#[test]
+fn test_multiple_result_sets_rejected() {
+    let Value::Bulk(mut values) = int_rows(&[1]) else { unreachable!() };
+    values.push(Value::Bulk(vec![]));
+    let err = GraphResponse::<(i64,)>::parse_response(&Value::Bulk(values)).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::InvalidResponse));
+    assert!(err.detail().unwrap().contains("length 4"));
+}
+
+#[test]
+fn test_unknown_value_type() {
+    let value = Value::Bulk(vec![Value::Int(42), Value::Int(1)]);
+    assert_eq!(redis::from_redis_value::<GraphValue>(&value).unwrap(), GraphValue::Unknown(Value::Int(1)));
+
+    let nested = mock_node(1, &[0], vec![(0, mock_array(vec![value.clone()]))]);
+    for cell in [value, nested] {
+        let response = mock_response(&["x"], vec![vec![cell]], &[]);
+        assert!(GraphResponse::<(GraphValue,)>::parse_response(&response).is_ok());
+        let err = GraphResponse::<(GraphValue,)>::parse_response_strict(&response).unwrap_err();
+        assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::UnknownValueType));
+        assert!(err.detail().unwrap().contains("42"));
+    }
+
+    // Type id 0 is parsed as GraphValue::Unknown as well so strict mode has to reject it
+    let unknown = mock_response(&["x"], vec![vec![Value::Bulk(vec![Value::Int(0), Value::Int(1)])]], &[]);
+    let err = GraphResponse::<(GraphValue,)>::parse_response_strict(&unknown).unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::UnknownValueType));
+
+    let known = mock_response(&["x"], vec![vec![mock_map(vec![("a", mock_integer(1))])]], &[]);
+    assert!(GraphResponse::<(GraphMap,)>::parse_response_strict(&known).is_ok());
+}
+
+#[test]
+fn test_mock_values() {
+    let node: Node = redis::from_redis_value(&untyped(mock_node(
+        1,
+        &[0, 2],
+        vec![(0, mock_string("a")), (1, mock_double(1.5)), (2, mock_null())],
+    ))).unwrap();
+    assert_eq!(node.id, 1);
+    assert_eq!(node.label_ids, vec![0, 2]);
+    assert_eq!(node.properties.get_index(1), Some((&1, &GraphValue::Double(1.5))));
+    assert_eq!(node.properties[&2], GraphValue::Null);
+
+    let value: GraphValue = redis::from_redis_value(&mock_path(
+        vec![mock_node(1, &[], vec![]), mock_node(2, &[], vec![])],
+        vec![mock_relationship(3, 0, 1, 2, vec![(0, mock_bool(true))])],
+    )).unwrap();
+    let path: GraphPath = from_graph_value(value).unwrap();
+    assert_eq!(path.nodes.len(), 2);
+    assert_eq!(path.relationships[0].properties[&0], GraphValue::Boolean(true));
+
+    let reply = mock_response(
+        &["a", "b"],
+        vec![vec![
+            mock_array(vec![mock_integer(1), mock_point(1.5, 2.5)]),
+            mock_map(vec![("k", mock_string("v"))]),
+        ]],
+        &["Cached execution: 1"],
+    );
+    let response: GraphResponse<(Vec<GraphValue>, HashMap<String, String>)> = redis::from_redis_value(&reply).unwrap();
+    let (array, map) = response.single().unwrap();
+    assert_eq!(array, vec![GraphValue::Integer(1), GraphValue::Point(GeoPoint { latitude: 1.5, longitude: 2.5 })]);
+    assert_eq!(map["k"], "v");
+}
+
+/// Strips the type of a mocked value to get the raw reply of e.g. a node
+fn untyped(value: Value) -> Value {
+    match value {
+        Value::Bulk(mut items) => items.pop().unwrap(),
+        value => value,
+    }
+}
+
+#[test]
+fn test_clone_response() {
+    let response = GraphResponse::<(i64,)>::parse_response(&int_rows(&[1, 2])).unwrap();
+    let cached = response.clone();
+    assert_eq!(cached, response);
+    assert_eq!(cached.data, vec![(1,), (2,)]);
+}
+
+#[test]
+fn test_map_rows() {
+    let parse = || GraphResponse::<(i64,)>::parse_response(&int_rows(&[1, 2, 3])).unwrap();
+    assert_eq!(parse().map_rows(|(x,)| x * x), vec![1, 4, 9]);
+    assert_eq!(parse().try_map_rows(|(x,)| Ok(x * x)).unwrap(), vec![1, 4, 9]);
+    let err = parse()
+        .try_map_rows(|(x,)| u8::try_from(x * 100).map_err(|_| GraphError::IntegerOverflow.into()))
+        .unwrap_err();
+    assert_eq!(GraphError::from_redis_error(&err), Some(GraphError::IntegerOverflow));
+}
+
+#[test]
+fn test_compare_responses() {
+    let parse = |reply: &Value| GraphResponse::<(String,)>::parse_response(reply).unwrap();
+    assert_eq!(parse(&string_rows(&["a", "b"])), parse(&string_rows(&["a", "b"])));
+    assert_ne!(parse(&string_rows(&["a", "b"])), parse(&string_rows(&["a"])));
+    let with_stats = mock_response(&["a"], vec![vec![mock_string("a")]], &["Cached execution: 1"]);
+    assert_ne!(parse(&with_stats), parse(&string_rows(&["a"])));
+    fn assert_eq_impl<T: Eq>() {}
+    assert_eq_impl::<GraphResponse<(String,)>>();
+}
+
+#[test]
+fn test_column_types() {
+    let column = |type_: i64, name: &str| Value::Bulk(vec![Value::Int(type_), Value::Data(name.as_bytes().to_vec())]);
+    let response = Value::Bulk(vec![
+        Value::Bulk(vec![column(2, "n"), column(1, "n.name")]),
+        Value::Bulk(vec![]),
+        Value::Bulk(vec![]),
+    ]);
+    let response = GraphResponse::<(Node, String)>::parse_response(&response).unwrap();
+    assert_eq!(response.column_types(), vec![ColumnType::Node, ColumnType::Scalar]);
+    assert_eq!(response.header_names(), vec!["n", "n.name"]);
+}
+
+#[test]
+fn test_header_names() {
+    let response: GraphResponse<(i64,)> = sync_con().graph_query("test", query!("RETURN 1 AS a")).unwrap();
+    assert_eq!(response.header_names(), vec!["a"]);
 }
@@ -1,6 +1,8 @@
-use redis::{FromRedisValue, Value, RedisResult, from_redis_value};
+use std::{borrow::Cow, collections::HashMap, fmt::Write, time::Duration};
 
-use crate::{GraphValue, helpers::{create_rediserror, apply_macro}, FromGraphValue, from_graph_value};
+use redis::{cmd, Cmd, FromRedisValue, Value, RedisResult, from_redis_value};
+
+use crate::{GeoPoint, GraphValue, parse::check_value_types, helpers::{apply_macro, cypher_identifier, escape_cypher_string, is_cypher_identifier}, FromGraphValue, from_graph_value, GraphError};
 
 /// ## Overview
 /// Response type from redis graph
@@ -10,12 +12,12 @@ use crate::{GraphValue, helpers::{create_rediserror, apply_macro}, FromGraphValu
 /// Will return a Vec of whatever stands in the return clause
 /// but the generic type in this case should not be `Node` it should be `(Node,)`
 /// as there can be multiple comma seperated items returned by a single matched pattern.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GraphResponse<T = GraphValue> where T: FromGraphValue {
-    /// List of return type names e.g. RETURN item, otheritem
-    /// will result in vec!["item", "otheritem"]
-    /// This info is largely useless
-    pub header: Vec<String>,
+    /// List of return types and names e.g. RETURN item, otheritem
+    /// will result in vec![(ColumnType::Scalar, "item"), (ColumnType::Scalar, "otheritem")]
+    /// Use `header_names` if only the names are needed
+    pub header: Vec<(ColumnType, String)>,
     /// Every item of data represents one match of a request
     /// each T is than the type returned by the return clause which is always a tuple-
     /// Even for RETURN 1 should be (i32, ) not i32 as there can always be multiple items
@@ -26,7 +28,7 @@ pub struct GraphResponse<T = GraphValue> where T: FromGraphValue {
 }
 
 impl<T: FromGraphValue> GraphResponse<T> {
-    fn parse_header(header: Vec<Value>) -> Vec<String> {
+    pub(crate) fn parse_header(header: Vec<Value>) -> RedisResult<Vec<(ColumnType, String)>> {
         // Somehow it is not possible to let redis convert the header to Vec<(i64, String)> on its own,
         // because it internally calls from_redis_values for tuple which has a weird internal implementation
         // which chunks the array and tries to collect every chunk as a (i64, String) Tuple when it should really just collect each item as a Tuple
@@ -34,11 +36,14 @@ impl<T: FromGraphValue> GraphResponse<T> {
         header
             .into_iter()
             .map(|v|
-                from_redis_value::<(i64, String)>(&v).unwrap().1
+                from_redis_value::<(i64, String)>(&v).map(|(type_, name)| (ColumnType::from_id(type_), name))
             ).collect()
     }
 
-    /// Parses a `redis::Value` into a `RedisResult<GraphResponse<T>>`
+    /// Parses a `redis::Value` into a `RedisResult<GraphResponse<T>>`.
+    /// Redis graph answers every query with a single result set, even if it contains multiple `RETURN` clauses
+    /// combined with `UNION` or `CALL` subqueries, which is either only the statistics or the header, rows and statistics.
+    /// Replies with any other number of top level elements are rejected with `GraphError::InvalidResponse`
     pub fn parse_response(value: &Value) -> RedisResult<GraphResponse<T>> {
         match value {
             Value::Bulk(ref values) => {
@@ -52,41 +57,179 @@ impl<T: FromGraphValue> GraphResponse<T> {
                         let (header, temp, statistics): (Vec<Value>, Vec<Vec<GraphValue>>, Vec<String>) = from_redis_value(value)?;
                         
                         Ok(GraphResponse {
-                            header: <GraphResponse>::parse_header(header),
+                            header: <GraphResponse>::parse_header(header)?,
                             data: temp.into_iter().map(|arr|
                                 from_graph_value(GraphValue::Array(arr))
                               ).collect::<RedisResult<_>>()?,
                             statistics
                         })
                     },
-                    len => Err(create_rediserror(&format!("Can't parse response of length {} to GraphResponse", len)))
+                    len => Err(GraphError::InvalidResponse.with_detail(format!("Can't parse response of length {} to GraphResponse, expected a single result set of 1 or 3 elements", len)))
                 }
             },
-            _ => Err(create_rediserror("Invalid Response from Redis"))
+            _ => Err(GraphError::InvalidResponse.with_detail("Invalid Response from Redis"))
+        }
+    }
+
+    /// Parses a `redis::Value` like `parse_response` but fails with `GraphError::UnknownValueType`
+    /// instead of returning `GraphValue::Unknown` if any returned value has a type id this client does not know about.
+    /// Useful to detect a redis graph version which is newer than this client
+    pub fn parse_response_strict(value: &Value) -> RedisResult<GraphResponse<T>> {
+        if let Value::Bulk(values) = value {
+            if let [_, Value::Bulk(rows), _] = values.as_slice() {
+                for row in rows {
+                    if let Value::Bulk(cells) = row {
+                        cells.iter().try_for_each(check_value_types)?;
+                    }
+                }
+            }
         }
+        GraphResponse::parse_response(value)
+    }
+
+    /// Takes ownership of the parsed rows
+    pub fn into_data(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Takes ownership of the parsed rows and transforms each of them with the closure
+    pub fn map_rows<U, F: FnMut(T) -> U>(self, f: F) -> Vec<U> {
+        self.data.into_iter().map(f).collect()
+    }
+
+    /// Same as `map_rows` but stops at the first row the closure fails for
+    pub fn try_map_rows<U, F: FnMut(T) -> RedisResult<U>>(self, f: F) -> RedisResult<Vec<U>> {
+        self.data.into_iter().map(f).collect()
+    }
+
+    /// Splits the response into the header names, the parsed rows and the parsed statistics
+    pub fn into_parts(self) -> (Vec<String>, Vec<T>, GraphStatistics) {
+        let statistics = self.get_statistics();
+        let header = self.header.into_iter().map(|(_, name)| name).collect();
+        (header, self.data, statistics)
+    }
+
+    /// First returned row if there is any
+    pub fn first(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Takes the only returned row and fails if there are no or multiple rows
+    pub fn single(self) -> RedisResult<T> {
+        let len = self.data.len();
+        let mut data = self.data.into_iter();
+        match (data.next(), data.next()) {
+            (Some(row), None) => Ok(row),
+            _ => Err(GraphError::LengthMismatch.with_detail(format!("Expected exactly one row but got {}", len))),
+        }
+    }
+
+    /// Number of returned rows
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if the query did not return any rows
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Names of the returned columns e.g. RETURN item, otheritem
+    /// will result in vec!["item", "otheritem"].
+    /// Prefer this over reading `header` directly as it does not depend on how the header is stored
+    pub fn header_names(&self) -> Vec<&str> {
+        self.header.iter().map(|(_, name)| name.as_str()).collect()
+    }
+
+    /// Types of the returned columns
+    pub fn column_types(&self) -> Vec<ColumnType> {
+        self.header.iter().map(|(type_, _)| *type_).collect()
     }
 
     /// Try to get the value of the requested statistic
     pub fn get_statistic(&self, stat: GraphStatistic) -> Option<f64> {
-        let start = stat.match_name();
-        for str in self.statistics.iter() {
-            if str.starts_with(start) {
-                let (_, val) = str.split_once(": ")?;
-                let val = val.split_once(' ').map_or(val, |x| x.0);
-                return Some(val.parse().unwrap())
+        stat.find_in(&self.statistics)
+    }
+
+    /// Parses all statistics of the response into a [`GraphStatistics`]
+    pub fn get_statistics(&self) -> GraphStatistics {
+        GraphStatistics::parse(&self.statistics)
+    }
+}
+
+impl GraphResponse<GraphValue> {
+    /// Parses a `redis::Value` like `parse_response` but every row becomes a `GraphValue::Array`
+    /// and cells which can not be parsed are kept as `GraphValue::Unknown` containing the raw cell instead of failing.
+    /// Only a reply without the structure of a graph response is an error
+    pub fn parse_lenient(value: &Value) -> RedisResult<GraphResponse> {
+        match value {
+            Value::Bulk(values) if values.len() == 3 => {
+                let (header, rows, statistics): (Vec<Value>, Vec<Vec<Value>>, Vec<String>) = from_redis_value(value)?;
+                Ok(GraphResponse {
+                    header: <GraphResponse>::parse_header(header)?,
+                    data: rows
+                        .into_iter()
+                        .map(|row| GraphValue::Array(row.into_iter().map(|cell| {
+                            GraphValue::from_redis_value(&cell).unwrap_or(GraphValue::Unknown(cell))
+                        }).collect()))
+                        .collect(),
+                    statistics,
+                })
             }
+            value => GraphResponse::parse_response(value),
         }
-        None
     }
 }
 
-
 impl<T: FromGraphValue> FromRedisValue for GraphResponse<T> {
     fn from_redis_value(v: &Value) -> RedisResult<GraphResponse<T>> {
         GraphResponse::parse_response(v)
     }
 }
 
+impl<T: FromGraphValue> IntoIterator for GraphResponse<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T: FromGraphValue> IntoIterator for &'a GraphResponse<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+/// Type of a returned column as reported in the header of a compact response.
+/// [Official enum](https://github.com/RedisGraph/RedisGraph/blob/master/src/resultset/formatters/resultset_formatter.h)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColumnType {
+    /// Column type is unknown
+    Unknown,
+    /// Column contains scalar values
+    Scalar,
+    /// Column contains nodes
+    Node,
+    /// Column contains relationships
+    Relation,
+}
+
+impl ColumnType {
+    fn from_id(id: i64) -> ColumnType {
+        match id {
+            1 => ColumnType::Scalar,
+            2 => ColumnType::Node,
+            3 => ColumnType::Relation,
+            _ => ColumnType::Unknown,
+        }
+    }
+}
+
 /// Execution statistics
 pub enum GraphStatistic {
     /// Number of labels added
@@ -112,6 +255,15 @@ pub enum GraphStatistic {
 }
 
 impl GraphStatistic {
+    /// Finds the line of this statistic and parses its value
+    fn find_in<S: AsRef<str>>(&self, statistics: &[S]) -> Option<f64> {
+        let start = self.match_name();
+        let line = statistics.iter().map(AsRef::as_ref).find(|line| line.starts_with(start))?;
+        let (_, val) = line.split_once(": ")?;
+        let val = val.split_once(' ').map_or(val, |x| x.0);
+        val.parse().ok()
+    }
+
     #[inline]
     pub(crate) const fn match_name(&self) -> &'static str{
         match self {
@@ -129,19 +281,193 @@ impl GraphStatistic {
     }
 }
 
+/// Typed execution statistics parsed from the statistics of a [`GraphResponse`].
+/// Counters which are missing in the response are 0
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GraphStatistics {
+    /// Number of labels added
+    pub labels_added: u64,
+    /// Number of nodes created
+    pub nodes_created: u64,
+    /// Number of relationships created
+    pub relationships_created: u64,
+    /// Number of indices created
+    pub indices_created: u64,
+    /// Number of properties set
+    pub properties_set: u64,
+    /// Number of nodes deleted
+    pub nodes_deleted: u64,
+    /// Number of relationships deleted
+    pub relationships_deleted: u64,
+    /// Number of indices deleted
+    pub indices_deleted: u64,
+    /// Whether the query was cached
+    pub cached_execution: bool,
+    /// Internal execution time of the redis server in milliseconds
+    pub execution_time_ms: Option<f64>,
+}
+
+impl GraphStatistics {
+    /// Parses the raw statistics of a response e.g. `["Nodes created: 1", "Cached execution: 0"]`.
+    /// Unknown or malformed lines are ignored
+    pub fn parse<S: AsRef<str>>(statistics: &[S]) -> GraphStatistics {
+        let count = |stat: GraphStatistic| stat.find_in(statistics).map_or(0, |val| val as u64);
+        GraphStatistics {
+            labels_added: count(GraphStatistic::LabelsAdded),
+            nodes_created: count(GraphStatistic::NodesCreated),
+            relationships_created: count(GraphStatistic::RelationshipsCreated),
+            indices_created: count(GraphStatistic::IndicesCreated),
+            properties_set: count(GraphStatistic::PropertiesSet),
+            nodes_deleted: count(GraphStatistic::NodesDeleted),
+            relationships_deleted: count(GraphStatistic::RelationshipsDeleted),
+            indices_deleted: count(GraphStatistic::IndicesDeleted),
+            cached_execution: count(GraphStatistic::CachedExecution) == 1,
+            execution_time_ms: GraphStatistic::ExecutionTime.find_in(statistics),
+        }
+    }
+
+    /// Internal execution time of the redis server as a [`Duration`].
+    /// Returns `None` if the time is missing or not a valid duration
+    pub fn execution_time(&self) -> Option<Duration> {
+        self.execution_time_ms
+            .and_then(|ms| Duration::try_from_secs_f64(ms / 1000.0).ok())
+    }
+}
+
+/// One operation of a profiled query as returned by GRAPH.PROFILE
+/// e.g. "    Node By Label Scan | (n:User) | Records produced: 3, Execution time: 0.004 ms"
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileStep {
+    /// Name of the operation e.g. "Node By Label Scan"
+    pub operation: String,
+    /// Number of records produced by the operation
+    pub records_produced: Option<u64>,
+    /// Execution time of the operation in milliseconds
+    pub execution_time: Option<f64>,
+    /// The unparsed line including the indentation which represents the depth in the plan
+    pub raw: String,
+}
+
+impl ProfileStep {
+    /// Parses a single line of the GRAPH.PROFILE reply.
+    /// Parts that do not match the known format are left as `None`
+    pub fn parse(line: &str) -> ProfileStep {
+        let mut parts = line.split('|').map(str::trim);
+        let operation = parts.next().unwrap_or_default().to_owned();
+        let mut records_produced = None;
+        let mut execution_time = None;
+        for stat in parts.flat_map(|part| part.split(',')) {
+            match stat.split_once(':') {
+                Some((name, value)) if name.trim() == "Records produced" => {
+                    records_produced = value.trim().parse().ok();
+                }
+                Some((name, value)) if name.trim() == "Execution time" => {
+                    let value = value.trim();
+                    execution_time = value.strip_suffix("ms").unwrap_or(value).trim().parse().ok();
+                }
+                _ => {}
+            }
+        }
+        ProfileStep {
+            operation,
+            records_produced,
+            execution_time,
+            raw: line.to_owned(),
+        }
+    }
+}
+
+impl FromRedisValue for ProfileStep {
+    fn from_redis_value(v: &Value) -> RedisResult<ProfileStep> {
+        Ok(ProfileStep::parse(&String::from_redis_value(v)?))
+    }
+}
+
+/// One entry of the slowlog of a graph as returned by GRAPH.SLOWLOG
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlowlogEntry {
+    /// Unix timestamp in seconds at which the command was executed
+    pub timestamp: i64,
+    /// The executed command e.g. "GRAPH.QUERY"
+    pub command: String,
+    /// The query of the command
+    pub query: String,
+    /// Execution time of the command in milliseconds
+    pub latency: f64,
+}
+
+impl FromRedisValue for SlowlogEntry {
+    fn from_redis_value(v: &Value) -> RedisResult<SlowlogEntry> {
+        let (timestamp, command, query, latency) = from_redis_value(v)?;
+        Ok(SlowlogEntry {
+            timestamp,
+            command,
+            query,
+            latency,
+        })
+    }
+}
+
+/// Index of a graph as returned by `CALL db.indexes()`
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphIndex {
+    /// Label or relationship type the index belongs to
+    pub label: String,
+    /// Indexed properties
+    pub properties: Vec<String>,
+    /// Type of the index per property e.g. `{name: ["RANGE"]}`, older redis graph versions return a list like `["exact-match"]`
+    pub types: GraphValue,
+    /// Whether the index is on nodes or relationships e.g. "NODE"
+    pub entity_type: String,
+    /// Construction status e.g. "OPERATIONAL" or "UNDER CONSTRUCTION"
+    pub status: String,
+}
+
+impl FromGraphValue for GraphIndex {
+    fn from_graph_value(value: GraphValue) -> RedisResult<Self> {
+        let (label, properties, types, entity_type, status) = from_graph_value(value)?;
+        Ok(GraphIndex {
+            label,
+            properties,
+            types,
+            entity_type,
+            status,
+        })
+    }
+}
+
 /// Contains information for constructing the query.
-/// Primarily generated by the [`query`] macro
-#[derive(PartialEq, Debug)]
+/// Primarily generated by the [`query`] macro.
+/// The default is an empty read-write query without parameters and timeout
+#[derive(PartialEq, Debug, Default)]
 pub struct GraphQuery {
-    /// The static query string
-    pub query: &'static str,
+    /// The query string which is either static or built at runtime
+    pub query: Cow<'static, str>,
     /// The dynamic Parameters to the query
     pub params: Vec<(&'static str, Parameter)>,
-    /// Whether or not the request should be read only
+    /// Whether or not the request should be read only.
+    /// Read only queries are sent as `GRAPH.RO_QUERY` which redis' cluster client does not know,
+    /// so like every other query they are routed to the primary that owns the slot of the graph and never to a replica
     pub read_only: bool,
+    /// Server side timeout of the query in milliseconds
+    pub timeout: Option<u64>,
 }
 
 impl GraphQuery {
+    /// Starts building a query without the [`query`] macro
+    /// ## Example
+    /// ```
+    /// use redisgraphio::{query, GraphQuery};
+    ///
+    /// let built = GraphQuery::builder("Return $a").param("a", 5).read_only(true).build();
+    /// assert_eq!(built, query!("Return $a", {"a" => 5}, true));
+    /// ```
+    pub fn builder<S: Into<Cow<'static, str>>>(query: S) -> GraphQueryBuilder {
+        GraphQueryBuilder {
+            query: GraphQuery { query: query.into(), ..Default::default() },
+        }
+    }
+
     pub(crate) fn read_type(&self) -> &'static str {
         if self.read_only {
             "GRAPH.RO_QUERY"
@@ -150,41 +476,149 @@ impl GraphQuery {
         }
     }
 
+    /// Builds the compact query command for the graph
+    pub(crate) fn to_cmd(&self, graph: &str) -> RedisResult<Cmd> {
+        let mut command = cmd(self.read_type());
+        command
+            .arg(graph)
+            .arg(self.checked_query()?)
+            .arg("--compact");
+        if let Some(timeout) = self.timeout {
+            command.arg("TIMEOUT").arg(timeout);
+        }
+        Ok(command)
+    }
+
+    /// Same as `construct_query` but fails with `GraphError::InvalidParameter` if a parameter name
+    /// is not a valid identifier as it would corrupt the `CYPHER` prefix
+    pub(crate) fn checked_query(&self) -> RedisResult<String> {
+        for (key, _) in &self.params {
+            if !is_cypher_identifier(key) {
+                return Err(GraphError::InvalidParameter.with_detail(format!(
+                    "{:?} is not a valid parameter name, expected letters, digits and underscores not starting with a digit",
+                    key
+                )));
+            }
+        }
+        Ok(self.construct_query())
+    }
+
     pub(crate) fn construct_query(&self) -> String {
-        self.parse_params() + &self.query
+        let mut query = String::with_capacity(self.query.len() + self.params.len() * 16);
+        self.write_params_prefix(&mut query);
+        query.push_str(&self.query);
+        query
+    }
+
+    /// The `CYPHER key=value ` prefix which passes the parameters to redis graph.
+    /// Empty if the query has no parameters
+    pub fn params_prefix(&self) -> String {
+        let mut prefix = String::with_capacity(self.params.len() * 16);
+        self.write_params_prefix(&mut prefix);
+        prefix
     }
 
-    fn parse_params(&self) -> String {
+    fn write_params_prefix(&self, out: &mut String) {
         if self.params.is_empty() {
-            return String::new();
+            return;
+        }
+        out.push_str("CYPHER ");
+        for (key, value) in &self.params {
+            out.push_str(key);
+            out.push('=');
+            value.write_cypher(out);
+            out.push(' ');
         }
-        let mut prepend = String::from("CYPHER ");
-        self.params.iter().for_each(|(key, value)| {
-            prepend.push_str(&match value {
-                Parameter::Int(int) => format!("{}={} ", key, int),
-                Parameter::Double(double) => format!("{}={} ", key, double),
-                Parameter::String(string) => format!(r#"{}="{}" "#, key, string.escape_default()),
-            });
-        });
-        prepend
     }
 
-    /// Adds a Parameter to the Parameter list that is escaped in the query 
+    /// Adds a Parameter to the Parameter list that is escaped in the query.
+    /// Sending the query fails if the key is not a valid identifier e.g. empty or starting with a digit
     pub fn add_parameter<T: Into<Parameter>>(&mut self, key: &'static str, value: T) -> &mut GraphQuery {
         self.params.push((key, value.into()));
         self
     }
 
+    /// Adds every Parameter of a collection built at runtime to the Parameter list
+    pub fn with_params<I, P>(&mut self, params: I) -> &mut GraphQuery
+    where
+        I: IntoIterator<Item = (&'static str, P)>,
+        P: Into<Parameter>,
+    {
+        self.params.extend(params.into_iter().map(|(key, value)| (key, value.into())));
+        self
+    }
+
     /// Set read only
     pub fn read_only(&mut self, read_only: bool) -> &mut GraphQuery {
         self.read_only = read_only;
         self
     }
+
+    /// Set the server side timeout in milliseconds after which redis graph aborts the query
+    pub fn timeout(&mut self, timeout: u64) -> &mut GraphQuery {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Marks the query as read only if it contains none of the keywords that can write to the graph.
+    /// Every procedure `CALL` counts as a write and keywords inside of strings are not ignored,
+    /// so in case of doubt the query stays read-write
+    pub fn infer_read_only(&mut self) -> &mut GraphQuery {
+        const WRITE_KEYWORDS: [&str; 7] = ["CREATE", "MERGE", "SET", "DELETE", "REMOVE", "CALL", "FOREACH"];
+        self.read_only = !self
+            .query
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .any(|word| WRITE_KEYWORDS.iter().any(|keyword| keyword.eq_ignore_ascii_case(word)));
+        self
+    }
+}
+
+/// Builder for a [`GraphQuery`] created by [`GraphQuery::builder`]
+#[derive(PartialEq, Debug)]
+pub struct GraphQueryBuilder {
+    query: GraphQuery,
+}
+
+impl GraphQueryBuilder {
+    /// Adds a Parameter that is escaped in the query
+    pub fn param<T: Into<Parameter>>(mut self, key: &'static str, value: T) -> Self {
+        self.query.add_parameter(key, value);
+        self
+    }
+
+    /// Set read only
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.query.read_only = read_only;
+        self
+    }
+
+    /// Set the server side timeout in milliseconds after which redis graph aborts the query
+    pub fn timeout(mut self, timeout: u64) -> Self {
+        self.query.timeout = Some(timeout);
+        self
+    }
+
+    /// Finishes the query
+    pub fn build(self) -> GraphQuery {
+        self.query
+    }
+}
+
+impl From<GraphQueryBuilder> for GraphQuery {
+    fn from(builder: GraphQueryBuilder) -> Self {
+        builder.build()
+    }
 }
 
 impl From<&'static str> for GraphQuery {
     fn from(query: &'static str) -> Self {
-        GraphQuery { query, params: vec![], read_only: false}
+        GraphQuery { query: Cow::Borrowed(query), ..Default::default() }
+    }
+}
+
+impl From<String> for GraphQuery {
+    fn from(query: String) -> Self {
+        GraphQuery { query: Cow::Owned(query), ..Default::default() }
     }
 }
 
@@ -199,6 +633,69 @@ pub enum Parameter {
     Int(i64),
     /// The Parameter is a Double
     Double(f64),
+    /// The Parameter is a Boolean
+    Boolean(bool),
+    /// The Parameter is null e.g. from a `None`
+    Null,
+    /// The Parameter is an Array of Parameters
+    Array(Vec<Parameter>),
+    /// The Parameter is a Map of keys to Parameters.
+    /// The entries are emitted in the order of the Vec
+    Map(Vec<(String, Parameter)>),
+    /// The Parameter is a Point
+    Point {
+        /// latitude
+        latitude: f64,
+        /// longitude
+        longitude: f64,
+    },
+    /// The Parameter is a cypher literal like `datetime('2020-01-01')` which is inserted as is.
+    /// It is neither quoted nor escaped so never construct it from untrusted input
+    Raw(String),
+}
+
+impl Parameter {
+    /// Writes the Parameter as a cypher literal
+    fn write_cypher(&self, out: &mut String) {
+        // Writing to a String never fails
+        match self {
+            Parameter::Int(int) => { let _ = write!(out, "{}", int); }
+            Parameter::Double(double) => { let _ = write!(out, "{}", double); }
+            Parameter::String(string) => {
+                out.push('"');
+                out.push_str(&escape_cypher_string(string));
+                out.push('"');
+            }
+            Parameter::Boolean(boolean) => { let _ = write!(out, "{}", boolean); }
+            Parameter::Null => out.push_str("null"),
+            Parameter::Array(values) => {
+                out.push('[');
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    value.write_cypher(out);
+                }
+                out.push(']');
+            }
+            Parameter::Map(entries) => {
+                out.push('{');
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    out.push_str(&cypher_identifier(key));
+                    out.push_str(": ");
+                    value.write_cypher(out);
+                }
+                out.push('}');
+            }
+            Parameter::Point { latitude, longitude } => {
+                let _ = write!(out, "point({{latitude: {}, longitude: {}}})", latitude, longitude);
+            }
+            Parameter::Raw(literal) => out.push_str(literal),
+        }
+    }
 }
 
 /// Macro for implementing the From Trait for a numeric type
@@ -225,6 +722,88 @@ macro_rules! parameter_from_double {
 apply_macro!(parameter_from_int, i8, i16, i32, i64, u8, u16, u32);
 apply_macro!(parameter_from_double, f32, f64);
 
+/// RedisGraph only stores signed 64 bit integers so values above `i64::MAX`
+/// saturate to `i64::MAX`, which is the same clamping the server applies to too large integer literals
+impl From<u64> for Parameter {
+    fn from(id: u64) -> Self {
+        Parameter::Int(i64::try_from(id).unwrap_or(i64::MAX))
+    }
+}
+
+impl From<bool> for Parameter {
+    fn from(boolean: bool) -> Self {
+        Parameter::Boolean(boolean)
+    }
+}
+
+impl<T: Into<Parameter>> From<Option<T>> for Parameter {
+    fn from(option: Option<T>) -> Self {
+        match option {
+            Some(value) => value.into(),
+            None => Parameter::Null,
+        }
+    }
+}
+
+impl<T: Into<Parameter>> From<Vec<T>> for Parameter {
+    fn from(values: Vec<T>) -> Self {
+        Parameter::Array(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<Parameter> + Clone> From<&[T]> for Parameter {
+    fn from(values: &[T]) -> Self {
+        Parameter::Array(values.iter().cloned().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<Parameter>, const N: usize> From<[T; N]> for Parameter {
+    fn from(values: [T; N]) -> Self {
+        Parameter::Array(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<Parameter>> From<Vec<(String, T)>> for Parameter {
+    fn from(entries: Vec<(String, T)>) -> Self {
+        Parameter::Map(entries.into_iter().map(|(key, value)| (key, value.into())).collect())
+    }
+}
+
+/// The entries are sorted by key so the constructed query is deterministic
+impl<T: Into<Parameter>> From<HashMap<String, T>> for Parameter {
+    fn from(map: HashMap<String, T>) -> Self {
+        let mut entries: Vec<(String, Parameter)> = map
+            .into_iter()
+            .map(|(key, value)| (key, value.into()))
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        Parameter::Map(entries)
+    }
+}
+
+impl From<GeoPoint> for Parameter {
+    fn from(point: GeoPoint) -> Self {
+        Parameter::Point { latitude: point.latitude, longitude: point.longitude }
+    }
+}
+
+/// Encodes the bytes as a standard base64 string like the conversion from a `GraphValue`
+#[cfg(feature = "base64")]
+impl From<crate::GraphBytes> for Parameter {
+    fn from(bytes: crate::GraphBytes) -> Self {
+        use base64::Engine;
+        Parameter::String(base64::engine::general_purpose::STANDARD.encode(bytes.0))
+    }
+}
+
+/// Emits the hyphenated lowercase form e.g. `"67e55044-10b1-426f-9247-bb680e5fe0c8"`
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for Parameter {
+    fn from(uuid: uuid::Uuid) -> Self {
+        Parameter::String(uuid.hyphenated().to_string())
+    }
+}
+
 impl<'a> From<&'a str> for Parameter {
     fn from(string: &'a str) -> Self {
         Parameter::String(string.to_string())